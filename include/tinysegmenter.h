@@ -0,0 +1,113 @@
+/* C API of the tinysegmenter library (libtinysegmenter). Generated from
+ * src/ffi.rs by cbindgen; do not edit. Bump ABI_VERSION with any change. */
+
+#ifndef TINYSEGMENTER_H
+#define TINYSEGMENTER_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+/*
+ Incremented whenever a function or type of the C API changes.
+ */
+#define TINYSEGMENTER_ABI_VERSION 1
+
+/*
+ An opaque segmenter handle.
+ */
+typedef struct TinySegmenter TinySegmenter;
+
+/*
+ One word: byte offsets `start..end` into the segmented text.
+ */
+typedef struct TsSpan {
+  size_t start;
+  size_t end;
+} TsSpan;
+
+/*
+ The words of one text. Owned by the library until passed to
+ `tinysegmenter_segments_free`.
+ */
+typedef struct TsSegments {
+  struct TsSpan *spans;
+  size_t len;
+} TsSegments;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/*
+ The version of the C API this library implements, `ABI_VERSION`.
+ */
+uint32_t tinysegmenter_abi_version(void);
+
+/*
+ The message of the last error on this thread, or NULL. Valid until the
+ next failing call on the same thread.
+ */
+const char *tinysegmenter_last_error(void);
+
+/*
+ A segmenter using the built-in model, or the small built-in model in
+ builds with only the `small-model` feature. Returns NULL and sets the last
+ error if the library was built with neither.
+ */
+struct TinySegmenter *tinysegmenter_new(void);
+
+/*
+ A segmenter using the model file at `path`, native or quantized. Returns
+ NULL and sets the last error on failure.
+
+ # Safety
+ `path` must be NULL or a NUL-terminated string.
+ */
+struct TinySegmenter *tinysegmenter_from_path(const char *path);
+
+/*
+ A segmenter using the model file contents `data[0..len]`, native or
+ quantized. Returns NULL and sets the last error on failure.
+
+ # Safety
+ `data` must point to `len` readable bytes.
+ */
+struct TinySegmenter *tinysegmenter_from_bytes(const uint8_t *data, size_t len);
+
+/*
+ Frees a segmenter. NULL is ignored.
+
+ # Safety
+ `segmenter` must be NULL or come from one of the constructors, and must
+ not be used afterwards.
+ */
+void tinysegmenter_free(struct TinySegmenter *segmenter);
+
+/*
+ Segments the UTF-8 text `text[0..len]` into `out`. Returns 0 on success;
+ otherwise -1, with `out` emptied and the last error set.
+
+ # Safety
+ `segmenter` must be a live handle, `text` must point to `len` readable
+ bytes and `out` must be writable. A segmenter may be used from several
+ threads at once.
+ */
+int tinysegmenter_segment(const struct TinySegmenter *segmenter,
+                          const char *text,
+                          size_t len,
+                          struct TsSegments *out);
+
+/*
+ Frees the spans of `segments` and empties it. Empty results and NULL are
+ ignored.
+
+ # Safety
+ `segments` must be NULL or filled by `tinysegmenter_segment`.
+ */
+void tinysegmenter_segments_free(struct TsSegments *segments);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* TINYSEGMENTER_H */
@@ -0,0 +1,122 @@
+// JavaScript API over the C API of a wasm32-unknown-unknown build of the
+// library. Offsets are UTF-16 offsets, as JavaScript indexes strings.
+
+const encoder = new TextEncoder();
+const decoder = new TextDecoder();
+
+/** Size of `TsSegments` and `TsSpan` on wasm32: two 32-bit fields each. */
+const PAIR = 8;
+
+/**
+ * Instantiates the module from its bytes, a `Response` or a promise of one,
+ * and resolves to a `TinySegmenterModule`.
+ */
+export async function init(source) {
+  source = await source;
+  const { instance } = typeof Response !== "undefined" && source instanceof Response
+    ? await WebAssembly.instantiateStreaming(source, {})
+    : await WebAssembly.instantiate(source, {});
+  return new TinySegmenterModule(instance.exports);
+}
+
+export class TinySegmenterModule {
+  constructor(exports) {
+    this.exports = exports;
+  }
+
+  /**
+   * A segmenter using the built-in model, the 8-bit one in a module built
+   * with `--features small-model`. Throws if the module has no built-in model.
+   */
+  segmenter() {
+    return this.handle(this.exports.tinysegmenter_new());
+  }
+
+  /**
+   * A segmenter using a model file, native or quantized, given as bytes or
+   * as a string. Throws with the library's message if it does not parse.
+   */
+  loadModel(model) {
+    const bytes = typeof model === "string" ? encoder.encode(model) : new Uint8Array(model);
+    return this.handle(this.withBytes(bytes, (data) => this.exports.tinysegmenter_from_bytes(data, bytes.length)));
+  }
+
+  /** Wraps a handle from the C API, throwing the last error if it is NULL. */
+  handle(handle) {
+    if (handle === 0) {
+      throw new Error(this.lastError());
+    }
+    return new Segmenter(this, handle);
+  }
+
+  /** Calls `f` with a copy of `bytes` in linear memory. */
+  withBytes(bytes, f) {
+    const data = this.exports.tinysegmenter_alloc(bytes.length);
+    new Uint8Array(this.exports.memory.buffer, data, bytes.length).set(bytes);
+    try {
+      return f(data);
+    } finally {
+      this.exports.tinysegmenter_dealloc(data, bytes.length);
+    }
+  }
+
+  lastError() {
+    const message = this.exports.tinysegmenter_last_error();
+    if (message === 0) {
+      return "unknown error";
+    }
+    const memory = new Uint8Array(this.exports.memory.buffer);
+    const end = memory.indexOf(0, message);
+    return decoder.decode(memory.subarray(message, end));
+  }
+}
+
+export class Segmenter {
+  constructor(module, handle) {
+    this.module = module;
+    this.handle = handle;
+  }
+
+  /** The words of `text`. */
+  segment(text) {
+    return this.segmentWithOffsets(text).map((token) => token.word);
+  }
+
+  /** The words of `text` as `{ word, start, end }`, with `text.slice(start, end) === word`. */
+  segmentWithOffsets(text) {
+    const { exports } = this.module;
+    const bytes = encoder.encode(text);
+    const out = exports.tinysegmenter_alloc(PAIR);
+    try {
+      return this.module.withBytes(bytes, (data) => {
+        if (exports.tinysegmenter_segment(this.handle, data, bytes.length, out) !== 0) {
+          throw new Error(this.module.lastError());
+        }
+        // Read after the call: linear memory may have grown and detached
+        // earlier views.
+        const view = new DataView(exports.memory.buffer);
+        const spans = view.getUint32(out, true);
+        const len = view.getUint32(out + 4, true);
+        const tokens = [];
+        let offset = 0;
+        for (let i = 0; i < len; i++) {
+          const start = view.getUint32(spans + i * PAIR, true);
+          const end = view.getUint32(spans + i * PAIR + 4, true);
+          const word = decoder.decode(bytes.subarray(start, end));
+          tokens.push({ word, start: offset, end: offset + word.length });
+          offset += word.length;
+        }
+        exports.tinysegmenter_segments_free(out);
+        return tokens;
+      });
+    } finally {
+      exports.tinysegmenter_dealloc(out, PAIR);
+    }
+  }
+
+  /** Frees the segmenter; it must not be used afterwards. */
+  free() {
+    this.module.exports.tinysegmenter_free(this.handle);
+    this.handle = 0;
+  }
+}
@@ -0,0 +1,300 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{construct_hashmap, get_key_tag, Model, Segmenter, Weights};
+
+/// What a token stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// A word of the segmentation.
+    Word,
+    /// Two adjacent characters of a kanji or katakana span, emitted by
+    /// `Analyzer::with_bigrams` whatever the word boundaries.
+    Bigram,
+}
+
+/// A token of the analyzed text. Offsets are byte offsets into the original
+/// text and stay there when a filter rewrites `text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub text: String,
+    pub offset_from: usize,
+    pub offset_to: usize,
+    /// The index of the word among all words of the text, counting the ones
+    /// filters dropped, so phrase matching does not jump over them. A bigram
+    /// takes the position of the word its first character belongs to.
+    pub position: usize,
+    pub kind: TokenKind,
+}
+
+/// One step of an `Analyzer`: rewrites a token in place, or drops it by
+/// returning `false`.
+pub trait TokenFilter {
+    fn apply(&self, token: &mut Token) -> bool;
+}
+
+/// Drops tokens found in a word list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StopWords {
+    words: HashSet<String>,
+}
+
+/// Particles, auxiliaries and other function words of little use to search.
+pub const JAPANESE_STOP_WORDS: [&str; 61] = [
+    "は", "が", "の", "を", "に", "へ", "と", "で", "や", "も", "か", "な", "ね", "よ", "から", "まで",
+    "より", "ば", "て", "た", "だ", "です", "ます", "まし", "ませ", "ん", "し", "する", "いる", "ある", "れる",
+    "られる", "せる", "ない", "ず", "う", "こと", "もの", "ため", "よう", "これ", "それ", "あれ", "この",
+    "その", "あの", "また", "および", "または", "ので", "のみ", "など", "という", "として", "について",
+    "において", "による", "によって", "に対して", "ところ", "ここ",
+];
+
+impl StopWords {
+    pub fn new<I, S>(words: I) -> StopWords
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        StopWords { words: words.into_iter().map(Into::into).collect() }
+    }
+
+    /// `JAPANESE_STOP_WORDS`.
+    pub fn japanese() -> StopWords {
+        StopWords::new(JAPANESE_STOP_WORDS)
+    }
+}
+
+impl TokenFilter for StopWords {
+    fn apply(&self, token: &mut Token) -> bool {
+        !self.words.contains(&token.text)
+    }
+}
+
+/// Strips punctuation and symbols from both ends of tokens and drops tokens
+/// left empty. Punctuation is what the character-type table leaves as other
+/// (`O`) and is not a letter or digit of some other script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Punctuation {
+    char_map: HashMap<char, char>,
+}
+
+impl Default for Punctuation {
+    fn default() -> Self {
+        Punctuation { char_map: construct_hashmap() }
+    }
+}
+
+impl Punctuation {
+    fn is_punctuation(&self, c: char) -> bool {
+        get_key_tag(&c, &self.char_map) == 'O' && !c.is_alphanumeric()
+    }
+}
+
+impl TokenFilter for Punctuation {
+    fn apply(&self, token: &mut Token) -> bool {
+        let trimmed = token.text.trim_matches(|c| self.is_punctuation(c));
+        if trimmed.len() != token.text.len() {
+            token.text = trimmed.to_string();
+        }
+        !token.text.is_empty()
+    }
+}
+
+/// Drops tokens shorter than `min` or longer than `max` characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Length {
+    pub min: usize,
+    pub max: usize,
+}
+
+impl Length {
+    pub fn at_least(min: usize) -> Length {
+        Length { min, max: usize::MAX }
+    }
+}
+
+impl TokenFilter for Length {
+    fn apply(&self, token: &mut Token) -> bool {
+        (self.min..=self.max).contains(&token.text.chars().count())
+    }
+}
+
+/// Rewrites tokens to one form per character: full-width ASCII to
+/// half-width, half-width katakana to full-width (voicing marks included)
+/// and, with `lowercase`, letters to lower case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Normalize {
+    pub width: bool,
+    pub lowercase: bool,
+}
+
+impl Default for Normalize {
+    fn default() -> Self {
+        Normalize { width: true, lowercase: true }
+    }
+}
+
+/// Full-width forms of U+FF61 to U+FF9D.
+const HALF_WIDTH_KATAKANA: &str = "。「」、・ヲァィゥェォャュョッーアイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワン";
+
+fn full_width(c: char) -> char {
+    let code = c as u32;
+    match code {
+        0xFF61..=0xFF9D => HALF_WIDTH_KATAKANA.chars().nth((code - 0xFF61) as usize).unwrap_or(c),
+        _ => c,
+    }
+}
+
+/// `base` with a dakuten (`voiced`) or a handakuten, if it takes one.
+fn voiced(base: char, voiced: bool) -> Option<char> {
+    match (base, voiced) {
+        ('ウ', true) => Some('ヴ'),
+        (_, true) if "カキクケコサシスセソタチツテトハヒフヘホ".contains(base) => char::from_u32(base as u32 + 1),
+        (_, false) if "ハヒフヘホ".contains(base) => char::from_u32(base as u32 + 2),
+        _ => None,
+    }
+}
+
+/// Combining and half-width voicing marks.
+fn is_voicing_mark(c: char) -> bool {
+    matches!(c, '\u{3099}' | '\u{309A}' | '\u{FF9E}' | '\u{FF9F}')
+}
+
+impl Normalize {
+    pub fn normalize(&self, text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        for c in text.chars() {
+            let mut c = c;
+            if self.width {
+                c = match c {
+                    '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+                    '\u{3000}' => ' ',
+                    '\u{FF9E}' | '\u{FF9F}' => {
+                        let previous = out.pop();
+                        match previous.and_then(|p| voiced(p, c == '\u{FF9E}')) {
+                            Some(combined) => combined,
+                            None => {
+                                out.extend(previous);
+                                if c == '\u{FF9E}' { '゛' } else { '゜' }
+                            }
+                        }
+                    }
+                    _ => full_width(c),
+                };
+            }
+            if self.lowercase {
+                out.extend(c.to_lowercase());
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+}
+
+impl TokenFilter for Normalize {
+    fn apply(&self, token: &mut Token) -> bool {
+        token.text = self.normalize(&token.text);
+        true
+    }
+}
+
+/// Segments text and passes every word through a chain of filters, in the
+/// order they were added.
+pub struct Analyzer<W = Model> {
+    segmenter: Segmenter<W>,
+    filters: Vec<Box<dyn TokenFilter>>,
+    bigrams: bool,
+}
+
+#[cfg(feature = "builtin-model")]
+impl Analyzer {
+    /// The Japanese search profile over the built-in model: `Normalize`,
+    /// then `Punctuation`, then `StopWords::japanese`.
+    pub fn search() -> Analyzer {
+        Analyzer::search_with(Segmenter::new())
+    }
+}
+
+impl<W: Weights> Analyzer<W> {
+    /// An analyzer without filters.
+    pub fn new(segmenter: Segmenter<W>) -> Analyzer<W> {
+        Analyzer { segmenter, filters: Vec::new(), bigrams: false }
+    }
+
+    /// The Japanese search profile over `segmenter`.
+    pub fn search_with(segmenter: Segmenter<W>) -> Analyzer<W> {
+        Analyzer::new(segmenter)
+            .filter(Normalize::default())
+            .filter(Punctuation::default())
+            .filter(StopWords::japanese())
+    }
+
+    pub fn filter<F: TokenFilter + 'static>(mut self, filter: F) -> Analyzer<W> {
+        self.filters.push(Box::new(filter));
+        self
+    }
+
+    /// Also emits every pair of adjacent kanji or katakana characters as a
+    /// `Bigram` token, for recall where the segmentation is wrong. Filters
+    /// apply to bigrams as to words.
+    pub fn with_bigrams(mut self) -> Analyzer<W> {
+        self.bigrams = true;
+        self
+    }
+
+    pub fn segmenter(&self) -> &Segmenter<W> {
+        &self.segmenter
+    }
+
+    /// The tokens of `text` that every filter keeps, ordered by offset with
+    /// words before the bigrams starting at the same character. Whitespace
+    /// separates words and is never part of a token.
+    pub fn analyze(&self, text: &str) -> Vec<Token> {
+        let mut tokens: Vec<Token> = self.segmenter.segment_spans(text).into_iter()
+            .enumerate()
+            .map(|(position, (text, span))| Token { text, offset_from: span.start, offset_to: span.end, position, kind: TokenKind::Word })
+            .collect();
+        if self.bigrams {
+            let bigrams = self.bigrams(&tokens);
+            tokens.extend(bigrams);
+            tokens.sort_by_key(|t| (t.offset_from, t.kind == TokenKind::Bigram));
+        }
+        tokens.into_iter()
+            .filter_map(|mut token| self.filters.iter().all(|f| f.apply(&mut token)).then_some(token))
+            .collect()
+    }
+
+    /// The bigrams of the kanji and katakana spans of `words`, with offsets
+    /// taken from the words' own. A voicing mark counts as part of the
+    /// character before it, so `ｶﾞｲ` pairs as `ｶﾞ` and `ｲ`. Characters whose
+    /// offsets do not touch are separated by whitespace and never paired.
+    fn bigrams(&self, words: &[Token]) -> Vec<Token> {
+        let spanned = |c: char| matches!(get_key_tag(&c, &self.segmenter.char_map), 'H' | 'M' | 'K');
+        // (start, end, text, first char, position) of each character.
+        let mut chars: Vec<(usize, usize, String, char, usize)> = Vec::new();
+        for w in words {
+            for (i, c) in w.text.char_indices() {
+                let start = w.offset_from + i;
+                let end = start + c.len_utf8();
+                match chars.last_mut() {
+                    Some(last) if is_voicing_mark(c) && last.1 == start => {
+                        last.1 = end;
+                        last.2.push(c);
+                    }
+                    _ => chars.push((start, end, c.to_string(), c, w.position)),
+                }
+            }
+        }
+        chars.windows(2)
+            .filter(|pair| pair[0].1 == pair[1].0 && spanned(pair[0].3) && spanned(pair[1].3))
+            .map(|pair| {
+                let text = format!("{}{}", pair[0].2, pair[1].2);
+                Token { text, offset_from: pair[0].0, offset_to: pair[1].1, position: pair[0].4, kind: TokenKind::Bigram }
+            })
+            .collect()
+    }
+
+    /// The texts of `analyze`.
+    pub fn terms(&self, text: &str) -> Vec<String> {
+        self.analyze(text).into_iter().map(|t| t.text).collect()
+    }
+}
@@ -0,0 +1,78 @@
+//! Generates a Rust module of static weight tables from a model file.
+//!
+//! Usage: `tinysegmenter-codegen <model-file> [-o <out.rs>]`
+//!
+//! The generated module exposes `TEMPLATES`, `BIAS` and `TABLES`, which can be
+//! turned into a runtime model with
+//! `tinysegmenter::Model::from_static(TEMPLATES, BIAS, TABLES)`, or with
+//! `tinysegmenter::compress::QuantizedModel::from_packed(TEMPLATES, BIAS, TABLES)`
+//! for a quantized model file.
+
+use std::env;
+use std::fs;
+use std::process;
+
+use tinysegmenter::compress::{generate_packed_module, quantized_width, QuantizedModel};
+use tinysegmenter::model::{generate_rust_module, ModelError};
+use tinysegmenter::Model;
+
+fn generate(input: &str) -> Result<String, ModelError> {
+    let text = fs::read_to_string(input)?;
+    match quantized_width(&text) {
+        Some("i8") => QuantizedModel::<i8>::parse(&text).map(|m| generate_packed_module(&m, input)),
+        Some("i16") => QuantizedModel::<i16>::parse(&text).map(|m| generate_packed_module(&m, input)),
+        Some(_) => Err(ModelError::Parse { line: 1, message: String::from("expected i8 or i16 weights") }),
+        None => {
+            let model = Model::parse(&text)?;
+            model.check_complete()?;
+            generate_rust_module(&model, input)
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut input = None;
+    let mut output = None;
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "-o" | "--output" => {
+                i += 1;
+                match args.get(i) {
+                    Some(path) => output = Some(path.clone()),
+                    None => {
+                        eprintln!("{} needs a file name", args[i - 1]);
+                        process::exit(2);
+                    }
+                }
+            }
+            path => input = Some(path.to_string()),
+        }
+        i += 1;
+    }
+    let input = match input {
+        Some(path) => path,
+        None => {
+            eprintln!("usage: tinysegmenter-codegen <model-file> [-o <out.rs>]");
+            process::exit(2);
+        }
+    };
+
+    let module = match generate(&input) {
+        Ok(module) => module,
+        Err(e) => {
+            eprintln!("{}: {}", input, e);
+            process::exit(1);
+        }
+    };
+    match output {
+        Some(path) => {
+            if let Err(e) = fs::write(&path, module) {
+                eprintln!("{}: {}", path, e);
+                process::exit(1);
+            }
+        }
+        None => print!("{}", module),
+    }
+}
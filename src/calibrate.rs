@@ -0,0 +1,90 @@
+use crate::eval::BoundaryScores;
+use crate::{predict_boundaries, Segmenter, SegmenterConfig, Weights};
+
+/// Thresholds tried by `calibrate_f1`, spread over the observed gap scores.
+const F1_CANDIDATES: usize = 200;
+
+/// Thresholds are searched within `±LIMIT`.
+const LIMIT: i32 = 1 << 24;
+
+fn token_length<W: Weights>(segmenter: &Segmenter<W>, texts: &[String], config: &SegmenterConfig) -> f64 {
+    let (mut chars, mut words) = (0, 0);
+    for text in texts {
+        chars += text.chars().count();
+        words += segmenter.segment_with(text, config).len();
+    }
+    if words == 0 { 0.0 } else { chars as f64 / words as f64 }
+}
+
+/// Mean number of characters per word when `segmenter` splits `texts`.
+pub fn average_token_length<W: Weights>(segmenter: &Segmenter<W>, texts: &[String]) -> f64 {
+    token_length(segmenter, texts, segmenter.config())
+}
+
+/// The segmenter's config with the threshold at which the words of `texts`
+/// come closest to `target` characters on average, and that average. Words
+/// get longer as the threshold rises, so the threshold is found by bisection.
+pub fn calibrate_token_length<W: Weights>(segmenter: &Segmenter<W>, texts: &[String], target: f64) -> (SegmenterConfig, f64) {
+    let at = |threshold: i32| {
+        let config = SegmenterConfig { threshold, ..*segmenter.config() };
+        (config, token_length(segmenter, texts, &config))
+    };
+
+    let (mut lo, mut hi) = (-1024, 1024);
+    while lo > -LIMIT && at(lo).1 > target {
+        lo *= 2;
+    }
+    while hi < LIMIT && at(hi).1 < target {
+        hi *= 2;
+    }
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if at(mid).1 < target { lo = mid } else { hi = mid }
+    }
+    let (lo, hi) = (at(lo), at(hi));
+    if (lo.1 - target).abs() <= (hi.1 - target).abs() { lo } else { hi }
+}
+
+fn scores_at<W: Weights>(segmenter: &Segmenter<W>, dev: &[Vec<String>], config: &SegmenterConfig) -> BoundaryScores {
+    let mut scores = BoundaryScores::default();
+    for gold in dev {
+        scores.add(gold, &segmenter.segment_with(&gold.concat(), config));
+    }
+    scores
+}
+
+/// The segmenter's config with the threshold giving the best boundary F1 on
+/// `dev`, and its scores. Candidates are spread over the gap scores the
+/// segmenter produces on `dev`; among equally good thresholds the one
+/// closest to zero wins.
+pub fn calibrate_f1<W: Weights>(segmenter: &Segmenter<W>, dev: &[Vec<String>]) -> (SegmenterConfig, BoundaryScores) {
+    let config = *segmenter.config();
+    let mut observed = Vec::new();
+    for gold in dev {
+        let text = gold.concat();
+        if !text.is_empty() {
+            predict_boundaries(&segmenter.sentence(&text, &config), &segmenter.model, &config, |_, score, _| observed.push(score));
+        }
+    }
+    observed.sort_unstable();
+    observed.dedup();
+
+    let mut candidates = vec![0, config.threshold];
+    if let Some(min) = observed.first() {
+        candidates.push(min.saturating_sub(1));
+    }
+    let step = observed.len().div_ceil(F1_CANDIDATES).max(1);
+    candidates.extend(observed.iter().step_by(step));
+    candidates.sort_by_key(|t| (t.unsigned_abs(), *t));
+    candidates.dedup();
+
+    let mut best: Option<(SegmenterConfig, BoundaryScores)> = None;
+    for threshold in candidates {
+        let config = SegmenterConfig { threshold, ..config };
+        let scores = scores_at(segmenter, dev, &config);
+        if best.is_none_or(|(_, b)| scores.f1() > b.f1()) {
+            best = Some((config, scores));
+        }
+    }
+    best.expect("the candidates are never empty")
+}
@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::model::{parse_template_line, sorted_entries, Model, ModelError, Weights};
+use crate::template::{self, Template};
+
+/// Drops every feature whose weight magnitude is below `min_weight`, then keeps
+/// at most `top_k` features per template, strongest first. Tables are kept even
+/// when they end up empty.
+pub fn prune(model: &Model, min_weight: i32, top_k: Option<usize>) -> Model {
+    let tables = model.tables.iter()
+        .map(|(name, table)| {
+            let mut entries: Vec<(&String, i32)> = sorted_entries(table).into_iter()
+                .filter(|(_, w)| w.unsigned_abs() >= min_weight.max(0) as u32)
+                .collect();
+            if let Some(k) = top_k {
+                entries.sort_by(|a, b| b.1.unsigned_abs().cmp(&a.1.unsigned_abs()).then_with(|| a.0.cmp(b.0)));
+                entries.truncate(k);
+            }
+            let table = entries.into_iter().map(|(k, w)| (k.clone(), w)).collect();
+            (name.clone(), table)
+        })
+        .collect();
+    Model { templates: model.templates.clone(), bias: model.bias, tables }
+}
+
+/// Integer types that quantized weights can be stored in.
+pub trait QuantizedWeight: Copy + Into<i32> {
+    /// Name used in the `QUANT` header of a model file.
+    const NAME: &'static str;
+    const MAX: i32;
+
+    /// Converts `v`, saturating at `-MAX..=MAX`.
+    fn saturate(v: i32) -> Self;
+}
+
+impl QuantizedWeight for i8 {
+    const NAME: &'static str = "i8";
+    const MAX: i32 = i8::MAX as i32;
+
+    fn saturate(v: i32) -> i8 {
+        v.clamp(-(i8::MAX as i32), i8::MAX as i32) as i8
+    }
+}
+
+impl QuantizedWeight for i16 {
+    const NAME: &'static str = "i16";
+    const MAX: i32 = i16::MAX as i32;
+
+    fn saturate(v: i32) -> i16 {
+        v.clamp(-(i16::MAX as i32), i16::MAX as i32) as i16
+    }
+}
+
+/// One template's weights, stored as `weight ≈ q * scale`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantizedTable<Q> {
+    pub scale: f32,
+    pub weights: HashMap<String, Q>,
+}
+
+impl<Q: QuantizedWeight> QuantizedTable<Q> {
+    fn dequantize(&self, q: Q) -> i32 {
+        (q.into() as f32 * self.scale).round() as i32
+    }
+}
+
+/// A model whose tables hold `i8` or `i16` weights with a per-template scale.
+/// The bias stays a full `i32`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantizedModel<Q> {
+    pub templates: Vec<Template>,
+    pub bias: i32,
+    pub tables: HashMap<String, QuantizedTable<Q>>,
+}
+
+impl<Q: QuantizedWeight> QuantizedModel<Q> {
+    /// Scales each table so that its largest weight maps to `Q::MAX`. Tables
+    /// that already fit are stored unscaled; features rounding to zero are dropped.
+    pub fn quantize(model: &Model) -> QuantizedModel<Q> {
+        let tables = model.tables.iter()
+            .map(|(name, table)| {
+                let max_abs = table.values().map(|w| w.unsigned_abs()).max().unwrap_or(0);
+                let scale = if max_abs <= Q::MAX as u32 { 1.0 } else { max_abs as f32 / Q::MAX as f32 };
+                let weights = table.iter()
+                    .map(|(k, w)| (k.clone(), Q::saturate((*w as f32 / scale).round() as i32)))
+                    .filter(|(_, q)| (*q).into() != 0)
+                    .collect();
+                (name.clone(), QuantizedTable { scale, weights })
+            })
+            .collect();
+        QuantizedModel { templates: model.templates.clone(), bias: model.bias, tables }
+    }
+
+    /// Expands the tables back to `i32` weights.
+    pub fn dequantize(&self) -> Model {
+        let tables = self.tables.iter()
+            .map(|(name, table)| {
+                let weights = table.weights.iter().map(|(k, q)| (k.clone(), table.dequantize(*q))).collect();
+                (name.clone(), weights)
+            })
+            .collect();
+        Model { templates: self.templates.clone(), bias: self.bias, tables }
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<QuantizedModel<Q>, ModelError> {
+        let text = fs::read_to_string(path)?;
+        QuantizedModel::parse(&text)
+    }
+
+    /// Parses the quantized native format: a `QUANT<TAB>i8` header, optional
+    /// `TEMPLATE<TAB>name<TAB>spec` lines as in a full model, `BIAS`, one
+    /// `SCALE<TAB>name<TAB>scale` line per table and `name<TAB>feature<TAB>q`
+    /// weight lines.
+    pub fn parse(text: &str) -> Result<QuantizedModel<Q>, ModelError> {
+        let parse_err = |line: usize, message: String| ModelError::Parse { line, message };
+        let mut templates = Vec::new();
+        let mut bias = None;
+        let mut tables: HashMap<String, QuantizedTable<Q>> = HashMap::new();
+        for (i, raw) in text.lines().enumerate() {
+            let line = i + 1;
+            if raw.trim().is_empty() || raw.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = raw.split('\t').collect();
+            match fields.as_slice() {
+                ["QUANT", width] if *width == Q::NAME => {}
+                ["QUANT", width] => return Err(parse_err(line, format!("expected {} weights, found {}", Q::NAME, width))),
+                ["BIAS", weight] => {
+                    bias = Some(weight.trim().parse().map_err(|_| parse_err(line, format!("invalid weight `{}`", weight)))?);
+                }
+                ["TEMPLATE", name, spec] => templates.push(parse_template_line(name, spec, line)?),
+                ["SCALE", name, scale] => {
+                    let scale = scale.trim().parse().map_err(|_| parse_err(line, format!("invalid scale `{}`", scale)))?;
+                    tables.entry(name.to_string()).or_insert_with(|| QuantizedTable { scale, weights: HashMap::new() }).scale = scale;
+                }
+                [name, feature, q] => {
+                    let q: i32 = q.trim().parse().map_err(|_| parse_err(line, format!("invalid weight `{}`", q)))?;
+                    if q.unsigned_abs() > Q::MAX as u32 {
+                        return Err(parse_err(line, format!("weight {} does not fit in {}", q, Q::NAME)));
+                    }
+                    let table = tables.get_mut(*name).ok_or_else(|| parse_err(line, format!("no SCALE for template `{}`", name)))?;
+                    table.weights.insert(feature.to_string(), Q::saturate(q));
+                }
+                _ => return Err(parse_err(line, format!("expected 2 or 3 tab-separated fields, found {}", fields.len()))),
+            }
+        }
+        let bias = bias.ok_or(ModelError::MissingBias)?;
+        if templates.is_empty() {
+            templates = template::default_templates();
+        }
+        Ok(QuantizedModel { templates, bias, tables })
+    }
+
+    pub fn to_native(&self) -> String {
+        let mut out = format!("QUANT\t{}\n", Q::NAME);
+        out.push_str(&template::to_native(&self.templates));
+        out.push_str(&format!("BIAS\t{}\n", self.bias));
+        let mut names: Vec<&String> = self.tables.keys().collect();
+        names.sort();
+        for name in names {
+            let table = &self.tables[name];
+            out.push_str(&format!("SCALE\t{}\t{}\n", name, table.scale));
+            let mut entries: Vec<(&String, i32)> = table.weights.iter().map(|(k, q)| (k, (*q).into())).collect();
+            entries.sort();
+            for (feature, q) in entries {
+                out.push_str(&format!("{}\t{}\t{}\n", name, feature, q));
+            }
+        }
+        out
+    }
+
+    pub fn feature_count(&self) -> usize {
+        self.tables.values().map(|t| t.weights.len()).sum()
+    }
+
+    /// Builds a model from the statics `tinysegmenter-codegen` generates for a
+    /// quantized model file.
+    pub fn from_packed(templates: &[(&str, &str)], bias: i32, tables: &[PackedTable<Q>]) -> QuantizedModel<Q> {
+        let templates = template::parse_all(templates).expect("generated templates are valid");
+        let tables = tables.iter()
+            .map(|(name, scale, features, weights)| {
+                let weights = features.split('\t').zip(weights.iter()).map(|(k, q)| (k.to_string(), *q)).collect();
+                (name.to_string(), QuantizedTable { scale: *scale, weights })
+            })
+            .collect();
+        QuantizedModel { templates, bias, tables }
+    }
+}
+
+impl<Q: QuantizedWeight> Weights for QuantizedModel<Q> {
+    fn templates(&self) -> &[Template] {
+        &self.templates
+    }
+
+    fn bias(&self) -> i32 {
+        self.bias
+    }
+
+    fn table_weight(&self, template: &str, feature: &str) -> Option<i32> {
+        let table = self.tables.get(template)?;
+        Some(table.weights.get(feature).map_or(0, |q| table.dequantize(*q)))
+    }
+}
+
+/// One table of a generated quantized model: its name, its scale, its
+/// features joined by tabs and their weights in the same order.
+pub type PackedTable<Q> = (&'static str, f32, &'static str, &'static [Q]);
+
+/// Renders `model` as a Rust module with `TEMPLATES`, `BIAS` and `TABLES`
+/// statics for `QuantizedModel::from_packed`. Each table is one string and
+/// one integer array, which keeps the embedded model small.
+pub fn generate_packed_module<Q: QuantizedWeight>(model: &QuantizedModel<Q>, source: &str) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("// @generated by tinysegmenter-codegen from {}. Do not edit.\n\n", source));
+    out.push_str("pub static TEMPLATES: &[(&str, &str)] = &[\n");
+    for template in &model.templates {
+        out.push_str(&format!("    ({:?}, {:?}),\n", template.name, template.spec()));
+    }
+    out.push_str("];\n\n");
+    out.push_str(&format!("pub const BIAS: i32 = {};\n\n", model.bias));
+    out.push_str(&format!("pub static TABLES: &[(&str, f32, &str, &[{}])] = &[\n", Q::NAME));
+    let mut names: Vec<&String> = model.tables.keys().collect();
+    names.sort();
+    for name in names {
+        let table = &model.tables[name];
+        let mut entries: Vec<(&String, i32)> = table.weights.iter().map(|(k, q)| (k, (*q).into())).collect();
+        entries.sort();
+        let features: Vec<&str> = entries.iter().map(|(k, _)| k.as_str()).collect();
+        let weights: Vec<String> = entries.iter().map(|(_, q)| q.to_string()).collect();
+        out.push_str(&format!("    ({:?}, {:?}, {:?}, &[{}]),\n", name, table.scale, features.join("\t"), weights.join(", ")));
+    }
+    out.push_str("];\n");
+    out
+}
+
+/// The weight width named in the `QUANT` header of a model file, if any.
+pub fn quantized_width(text: &str) -> Option<&str> {
+    let first = text.lines().find(|l| !l.trim().is_empty() && !l.starts_with('#'))?;
+    first.strip_prefix("QUANT\t").map(|w| w.trim())
+}
+
+/// Parses a model file in the native or the quantized format; quantized
+/// weights are expanded.
+pub fn parse_any(text: &str) -> Result<Model, ModelError> {
+    match quantized_width(text) {
+        Some("i8") => QuantizedModel::<i8>::parse(text).map(|q| q.dequantize()),
+        Some("i16") => QuantizedModel::<i16>::parse(text).map(|q| q.dequantize()),
+        Some(width) => Err(ModelError::Parse { line: 1, message: format!("unsupported weight width `{}`", width) }),
+        None => Model::parse(text),
+    }
+}
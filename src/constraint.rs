@@ -0,0 +1,81 @@
+use std::str::FromStr;
+
+use crate::Decision;
+
+/// Katakana-typed marks that only lengthen or voice the character before them.
+const MARKS: &str = "ーｰ\u{ff9e}";
+
+/// Which runs of one character type are kept as single words. `ー` has the
+/// katakana type, so it stays inside katakana runs, but it does not start
+/// one: in `すごーい` it lengthens hiragana and is left to the model.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RunGrouping {
+    /// Katakana runs (`K`), e.g. `コンピューター`.
+    pub katakana: bool,
+    /// Latin letter runs (`A`).
+    pub alphabet: bool,
+    /// Digit runs (`N`).
+    pub digits: bool,
+    /// Letters and digits together as one run, e.g. `iPhone15`.
+    pub alphanumeric: bool,
+}
+
+impl RunGrouping {
+    pub const NAMES: [&'static str; 4] = ["katakana", "alphabet", "digits", "alphanumeric"];
+
+    /// Every rule.
+    pub fn all() -> RunGrouping {
+        RunGrouping { katakana: true, alphabet: true, digits: true, alphanumeric: true }
+    }
+
+    /// The run a character of type `ctype` belongs to, if its type is grouped.
+    fn group(&self, ctype: char) -> Option<char> {
+        match ctype {
+            'K' if self.katakana => Some('K'),
+            'A' | 'N' if self.alphanumeric => Some('A'),
+            'A' if self.alphabet => Some('A'),
+            'N' if self.digits => Some('N'),
+            _ => None,
+        }
+    }
+
+    /// The decision forced at every gap of a text with characters `chars` of
+    /// types `ctype`: no boundary inside a grouped run. The edges of runs and
+    /// every other gap are left to the model.
+    pub(crate) fn constraints(&self, chars: &[String], ctype: &[char]) -> Vec<Option<Decision>> {
+        let mut groups: Vec<Option<char>> = Vec::with_capacity(ctype.len());
+        for (c, t) in chars.iter().zip(ctype) {
+            let group = self.group(*t);
+            let starts = groups.last() != Some(&group);
+            groups.push(if starts && MARKS.contains(c.as_str()) { None } else { group });
+        }
+        groups.windows(2)
+            .map(|pair| match (pair[0], pair[1]) {
+                (Some(a), Some(b)) if a == b => Some(Decision::Inside),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl FromStr for RunGrouping {
+    type Err = String;
+
+    /// A comma-separated list of rule names, or `all`.
+    fn from_str(s: &str) -> Result<RunGrouping, String> {
+        if s == "all" {
+            return Ok(RunGrouping::all());
+        }
+        let mut grouping = RunGrouping::default();
+        for name in s.split(',') {
+            match name.trim() {
+                "katakana" => grouping.katakana = true,
+                "alphabet" => grouping.alphabet = true,
+                "digits" => grouping.digits = true,
+                "alphanumeric" => grouping.alphanumeric = true,
+                other => return Err(format!("unknown run type `{}`; expected all or some of {}", other, RunGrouping::NAMES.join(", "))),
+            }
+        }
+        Ok(grouping)
+    }
+}
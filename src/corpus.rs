@@ -0,0 +1,269 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::eval::read_segmented;
+
+#[derive(Debug)]
+pub enum CorpusError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for CorpusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CorpusError::Io(e) => write!(f, "{}", e),
+            CorpusError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl std::error::Error for CorpusError {}
+
+impl From<io::Error> for CorpusError {
+    fn from(e: io::Error) -> Self {
+        CorpusError::Io(e)
+    }
+}
+
+/// Fully annotated corpus formats, all read as sentences of words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// One sentence per line, words separated by spaces.
+    Segmented,
+    /// MeCab output with the IPADIC feature layout: `surface<TAB>pos,...`
+    /// lines, each sentence ended by `EOS`.
+    Mecab,
+    /// UniDic-style TSV: the surface form in the first column, sentences ended
+    /// by `EOS` or a blank line.
+    Unidic,
+    /// CoNLL-U as used by UD Japanese-GSD: the FORM column of each word line.
+    Conllu,
+    /// KyTea full annotation: `word/pos/reading` tokens separated by spaces.
+    Kytea,
+}
+
+impl Format {
+    pub const NAMES: [&'static str; 5] = ["segmented", "mecab", "unidic", "conllu", "kytea"];
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "segmented" => Ok(Format::Segmented),
+            "mecab" => Ok(Format::Mecab),
+            "unidic" => Ok(Format::Unidic),
+            "conllu" => Ok(Format::Conllu),
+            "kytea" => Ok(Format::Kytea),
+            other => Err(format!("unknown corpus format `{}`; expected one of {}", other, Format::NAMES.join(", "))),
+        }
+    }
+}
+
+/// Reads a corpus in `format` into sentences of words.
+pub fn read(text: &str, format: Format) -> Result<Vec<Vec<String>>, CorpusError> {
+    match format {
+        Format::Segmented => Ok(read_segmented(text)),
+        Format::Mecab => read_mecab(text),
+        Format::Unidic => read_unidic(text),
+        Format::Conllu => read_conllu(text),
+        Format::Kytea => Ok(read_kytea(text)),
+    }
+}
+
+pub fn load<P: AsRef<Path>>(path: P, format: Format) -> Result<Vec<Vec<String>>, CorpusError> {
+    read(&fs::read_to_string(path)?, format)
+}
+
+/// Collects the words of `lines` into sentences, ending a sentence at `EOS`
+/// or a blank line. `word` returns the surface of a token line.
+fn read_tokens<F>(text: &str, mut word: F) -> Result<Vec<Vec<String>>, CorpusError>
+where
+    F: FnMut(usize, &str) -> Result<Option<String>, CorpusError>,
+{
+    let mut sentences = Vec::new();
+    let mut sentence = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() || line == "EOS" {
+            if !sentence.is_empty() {
+                sentences.push(std::mem::take(&mut sentence));
+            }
+        } else if let Some(w) = word(i + 1, line)? {
+            sentence.push(w);
+        }
+    }
+    if !sentence.is_empty() {
+        sentences.push(sentence);
+    }
+    Ok(sentences)
+}
+
+fn read_mecab(text: &str) -> Result<Vec<Vec<String>>, CorpusError> {
+    read_tokens(text, |line, raw| match raw.split_once('\t') {
+        Some((surface, _)) if !surface.is_empty() => Ok(Some(surface.to_string())),
+        _ => Err(CorpusError::Parse { line, message: String::from("expected `surface<TAB>features`") }),
+    })
+}
+
+fn read_unidic(text: &str) -> Result<Vec<Vec<String>>, CorpusError> {
+    read_tokens(text, |_, raw| {
+        let surface = raw.split('\t').next().unwrap_or("");
+        Ok(if raw.starts_with('#') || surface.is_empty() { None } else { Some(surface.to_string()) })
+    })
+}
+
+fn read_conllu(text: &str) -> Result<Vec<Vec<String>>, CorpusError> {
+    read_tokens(text, |line, raw| {
+        if raw.starts_with('#') {
+            return Ok(None);
+        }
+        let fields: Vec<&str> = raw.split('\t').collect();
+        if fields.len() != 10 {
+            let message = format!("expected 10 tab-separated fields, found {}", fields.len());
+            return Err(CorpusError::Parse { line, message });
+        }
+        // Multiword token ranges (`1-2`) and empty nodes (`1.1`) are not words
+        // of the surface text.
+        if fields[0].contains(['-', '.']) {
+            return Ok(None);
+        }
+        Ok(Some(fields[1].to_string()))
+    })
+}
+
+/// The word part of a KyTea `word/pos/reading` token, with `\` escapes removed.
+fn kytea_word(token: &str) -> String {
+    let mut word = String::new();
+    let mut chars = token.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => word.extend(chars.next()),
+            '/' => break,
+            _ => word.push(c),
+        }
+    }
+    word
+}
+
+fn read_kytea(text: &str) -> Vec<Vec<String>> {
+    text.lines()
+        .map(|line| line.split_whitespace().map(kytea_word).filter(|w| !w.is_empty()).collect::<Vec<String>>())
+        .filter(|words| !words.is_empty())
+        .collect()
+}
+
+/// The annotation of the gap between two adjacent characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gap {
+    /// A word boundary (`|`).
+    Boundary,
+    /// No boundary (`-`).
+    Inside,
+    /// Not annotated (a space).
+    Unknown,
+}
+
+impl Gap {
+    fn from_marker(c: char) -> Option<Gap> {
+        match c {
+            '|' => Some(Gap::Boundary),
+            '-' => Some(Gap::Inside),
+            ' ' => Some(Gap::Unknown),
+            _ => None,
+        }
+    }
+
+    pub fn marker(self) -> char {
+        match self {
+            Gap::Boundary => '|',
+            Gap::Inside => '-',
+            Gap::Unknown => ' ',
+        }
+    }
+}
+
+/// A sentence with some or all of its gaps annotated. `gaps[i]` is the gap
+/// after the `i + 1`th character, so there is one gap fewer than characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Annotation {
+    pub text: String,
+    pub gaps: Vec<Gap>,
+}
+
+impl Annotation {
+    /// A fully annotated sentence from its words.
+    pub fn from_words(words: &[String]) -> Annotation {
+        let mut gaps = Vec::new();
+        for (i, word) in words.iter().enumerate() {
+            if i > 0 {
+                gaps.push(Gap::Boundary);
+            }
+            gaps.extend(word.chars().skip(1).map(|_| Gap::Inside));
+        }
+        Annotation { text: words.concat(), gaps }
+    }
+
+    /// Parses one line of partial annotation: characters alternating with a
+    /// gap marker, e.g. `東-京|都 に-行`.
+    pub fn parse(line: &str) -> Result<Annotation, String> {
+        let mut text = String::new();
+        let mut gaps = Vec::new();
+        for (i, c) in line.chars().enumerate() {
+            if i % 2 == 0 {
+                text.push(c);
+                continue;
+            }
+            match Gap::from_marker(c) {
+                Some(gap) => gaps.push(gap),
+                None => return Err(format!("expected `|`, `-` or a space after character {}, found `{}`", i / 2 + 1, c)),
+            }
+        }
+        if text.is_empty() || gaps.len() == text.chars().count() {
+            return Err(String::from("line must start and end with a character"));
+        }
+        Ok(Annotation { text, gaps })
+    }
+
+    /// The inverse of `parse`.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for (i, c) in self.text.chars().enumerate() {
+            if i > 0 {
+                out.push(self.gaps[i - 1].marker());
+            }
+            out.push(c);
+        }
+        out
+    }
+
+    /// The annotation of the gap with `position` characters before it.
+    pub fn gap(&self, position: usize) -> Gap {
+        self.gaps.get(position.wrapping_sub(1)).copied().unwrap_or(Gap::Unknown)
+    }
+
+    pub fn labelled(&self) -> usize {
+        self.gaps.iter().filter(|g| **g != Gap::Unknown).count()
+    }
+}
+
+/// Reads a partially annotated corpus, one sentence per line. Blank lines are
+/// skipped.
+pub fn read_partial(text: &str) -> Result<Vec<Annotation>, CorpusError> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            Annotation::parse(line.trim_end_matches('\r')).map_err(|message| CorpusError::Parse { line: i + 1, message })
+        })
+        .collect()
+}
+
+pub fn load_partial<P: AsRef<Path>>(path: P) -> Result<Vec<Annotation>, CorpusError> {
+    read_partial(&fs::read_to_string(path)?)
+}
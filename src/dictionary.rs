@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Dictionary features distinguish word lengths up to this; longer words
+/// share the features of this length.
+pub const MAX_FEATURE_LENGTH: usize = 4;
+
+/// A word list for dictionary features. At every gap it reports the lengths
+/// of dictionary words that end right before the gap (`L`), start right after
+/// it (`R`) or span it (`I`), so `R2` means "a two-character word starts here".
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Dictionary {
+    words: HashSet<String>,
+    max_chars: usize,
+}
+
+impl Dictionary {
+    pub fn new<I, S>(words: I) -> Dictionary
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let words: HashSet<String> = words.into_iter().map(Into::into).filter(|w: &String| !w.is_empty()).collect();
+        let max_chars = words.iter().map(|w| w.chars().count()).max().unwrap_or(0);
+        Dictionary { words, max_chars }
+    }
+
+    /// Reads one word per line, taking the first tab-separated field so that
+    /// lexicons with extra columns can be used as they are. Blank lines and
+    /// lines starting with `#` are skipped.
+    pub fn parse(text: &str) -> Dictionary {
+        Dictionary::new(
+            text.lines()
+                .filter(|l| !l.trim().is_empty() && !l.starts_with('#'))
+                .map(|l| l.split('\t').next().unwrap_or("").trim()),
+        )
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Dictionary> {
+        Ok(Dictionary::parse(&fs::read_to_string(path)?))
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.contains(word)
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// The dictionary features of every gap of `chars`, indexed by the number
+    /// of characters before the gap.
+    pub(crate) fn gap_features(&self, chars: &[String]) -> Vec<Vec<String>> {
+        let mut features: Vec<Vec<String>> = vec![Vec::new(); chars.len() + 1];
+        let mut add = |position: usize, feature: String| {
+            if !features[position].contains(&feature) {
+                features[position].push(feature);
+            }
+        };
+        for start in 0..chars.len() {
+            let mut word = String::new();
+            for end in start + 1..=chars.len().min(start + self.max_chars) {
+                word.push_str(&chars[end - 1]);
+                if !self.words.contains(&word) {
+                    continue;
+                }
+                let len = (end - start).min(MAX_FEATURE_LENGTH);
+                add(end, format!("L{}", len));
+                add(start, format!("R{}", len));
+                for inside in start + 1..end {
+                    add(inside, format!("I{}", len));
+                }
+            }
+        }
+        for gap in &mut features {
+            gap.sort();
+        }
+        features
+    }
+}
@@ -0,0 +1,100 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::corpus::{Annotation, Gap};
+use crate::{Segmenter, Weights};
+
+/// Reads a space-segmented corpus: one sentence per line, words separated by
+/// whitespace. Blank lines are skipped.
+pub fn read_segmented(text: &str) -> Vec<Vec<String>> {
+    text.lines()
+        .map(|line| line.split_whitespace().map(String::from).collect::<Vec<String>>())
+        .filter(|words| !words.is_empty())
+        .collect()
+}
+
+pub fn load_segmented<P: AsRef<Path>>(path: P) -> io::Result<Vec<Vec<String>>> {
+    Ok(read_segmented(&fs::read_to_string(path)?))
+}
+
+/// Character offsets of the boundaries between words, excluding both ends.
+pub fn boundaries(words: &[String]) -> Vec<usize> {
+    let mut offsets = Vec::new();
+    let mut offset = 0;
+    for word in words.iter().take(words.len().saturating_sub(1)) {
+        offset += word.chars().count();
+        offsets.push(offset);
+    }
+    offsets
+}
+
+/// Boundary-level confusion counts.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct BoundaryScores {
+    pub true_positives: usize,
+    pub false_positives: usize,
+    pub false_negatives: usize,
+}
+
+impl BoundaryScores {
+    /// Counts the boundaries of `predicted` against those of `gold`.
+    pub fn add(&mut self, gold: &[String], predicted: &[String]) {
+        let gold = boundaries(gold);
+        let predicted = boundaries(predicted);
+        let hits = predicted.iter().filter(|b| gold.binary_search(b).is_ok()).count();
+        self.true_positives += hits;
+        self.false_positives += predicted.len() - hits;
+        self.false_negatives += gold.len() - hits;
+    }
+
+    /// Counts the boundaries of `predicted` at the labelled gaps of `gold` only.
+    pub fn add_annotated(&mut self, gold: &Annotation, predicted: &[String]) {
+        let predicted = boundaries(predicted);
+        for (i, gap) in gold.gaps.iter().enumerate() {
+            let split = predicted.binary_search(&(i + 1)).is_ok();
+            match (gap, split) {
+                (Gap::Boundary, true) => self.true_positives += 1,
+                (Gap::Boundary, false) => self.false_negatives += 1,
+                (Gap::Inside, true) => self.false_positives += 1,
+                _ => {}
+            }
+        }
+    }
+
+    pub fn precision(&self) -> f64 {
+        ratio(self.true_positives, self.true_positives + self.false_positives)
+    }
+
+    pub fn recall(&self) -> f64 {
+        ratio(self.true_positives, self.true_positives + self.false_negatives)
+    }
+
+    pub fn f1(&self) -> f64 {
+        let (p, r) = (self.precision(), self.recall());
+        if p + r == 0.0 { 0.0 } else { 2.0 * p * r / (p + r) }
+    }
+}
+
+fn ratio(n: usize, d: usize) -> f64 {
+    if d == 0 { 1.0 } else { n as f64 / d as f64 }
+}
+
+/// Segments the unsegmented text of every gold sentence and scores the result.
+pub fn evaluate<W: Weights>(segmenter: &Segmenter<W>, corpus: &[Vec<String>]) -> BoundaryScores {
+    let mut scores = BoundaryScores::default();
+    for gold in corpus {
+        let predicted = segmenter.segment(&gold.concat());
+        scores.add(gold, &predicted);
+    }
+    scores
+}
+
+/// Like `evaluate`, for partially annotated sentences.
+pub fn evaluate_annotated<W: Weights>(segmenter: &Segmenter<W>, corpus: &[Annotation]) -> BoundaryScores {
+    let mut scores = BoundaryScores::default();
+    for gold in corpus {
+        scores.add_annotated(gold, &segmenter.segment(&gold.text));
+    }
+    scores
+}
@@ -0,0 +1,111 @@
+use crate::{extract_features, get_score, predict_boundaries, Decision, Segmenter, Weights};
+
+/// One feature looked up for a gap, with the score accumulated so far.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contribution {
+    pub template: String,
+    pub feature: String,
+    pub weight: i32,
+    pub total: i32,
+}
+
+impl Contribution {
+    /// The feature written as `TEMPLATE:feature`, e.g. `UC4:H`.
+    pub fn key(&self) -> String {
+        format!("{}:{}", self.template, self.feature)
+    }
+}
+
+/// Why the segmenter did or did not split between two characters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GapExplanation {
+    /// Number of characters before the gap.
+    pub position: usize,
+    pub left: String,
+    pub right: String,
+    pub bias: i32,
+    pub contributions: Vec<Contribution>,
+    pub score: i32,
+    pub boundary: bool,
+}
+
+impl<W: Weights> Segmenter<W> {
+    /// Scores every gap of `text` like `segment` and records each feature lookup.
+    pub fn explain(&self, text: &str) -> Vec<GapExplanation> {
+        if text.is_empty() { return Vec::new(); }
+
+        let sentence = self.sentence(text, &self.config);
+        let chars = &sentence.chars;
+        let mut gaps = Vec::new();
+        let bias = self.config.bias(&self.model);
+        predict_boundaries(&sentence, &self.model, &self.config, |window, score, decision| {
+            let mut total = bias;
+            let contributions = extract_features(self.model.templates(), window).into_iter()
+                .map(|(template, feature)| {
+                    let weight = get_score(template, &feature, &self.model);
+                    total = total.saturating_add(weight);
+                    Contribution { template: template.to_string(), feature, weight, total }
+                })
+                .collect();
+            gaps.push(GapExplanation {
+                position: window.position,
+                left: chars[window.position - 1].clone(),
+                right: chars[window.position].clone(),
+                bias,
+                contributions,
+                score,
+                boundary: decision == Decision::Boundary,
+            });
+        });
+        gaps
+    }
+}
+
+/// Renders explanations as one table per gap.
+pub fn render_table(gaps: &[GapExplanation]) -> String {
+    let mut out = String::new();
+    for gap in gaps {
+        let decision = if gap.boundary { "boundary" } else { "no boundary" };
+        out.push_str(&format!("gap {} ({}|{}): score {} -> {}\n", gap.position, gap.left, gap.right, gap.score, decision));
+        out.push_str(&format!("  {:>8} {:>8}  {}\n", "weight", "total", "feature"));
+        out.push_str(&format!("  {:>8} {:>8}  {}\n", gap.bias, gap.bias, "BIAS"));
+        for c in &gap.contributions {
+            out.push_str(&format!("  {:>8} {:>8}  {}\n", c.weight, c.total, c.key()));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Renders explanations as a JSON array with one object per gap.
+pub fn render_json(gaps: &[GapExplanation]) -> String {
+    let items: Vec<String> = gaps.iter()
+        .map(|gap| {
+            let contributions: Vec<String> = gap.contributions.iter()
+                .map(|c| format!("{{\"template\":{},\"feature\":{},\"weight\":{},\"total\":{}}}",
+                                 json_string(&c.template), json_string(&c.feature), c.weight, c.total))
+                .collect();
+            format!("{{\"position\":{},\"left\":{},\"right\":{},\"bias\":{},\"score\":{},\"boundary\":{},\"features\":[{}]}}",
+                    gap.position, json_string(&gap.left), json_string(&gap.right), gap.bias, gap.score,
+                    gap.boundary, contributions.join(","))
+        })
+        .collect();
+    format!("[{}]", items.join(","))
+}
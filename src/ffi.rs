@@ -0,0 +1,216 @@
+use std::cell::RefCell;
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+
+use crate::compress::parse_any;
+use crate::{Model, Segmenter};
+
+/// Incremented whenever a function or type of the C API changes.
+pub const ABI_VERSION: u32 = 1;
+
+/// An opaque segmenter handle.
+pub struct TinySegmenter {
+    segmenter: Segmenter<Model>,
+}
+
+/// One word: byte offsets `start..end` into the segmented text.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TsSpan {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// The words of one text. Owned by the library until passed to
+/// `tinysegmenter_segments_free`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct TsSegments {
+    pub spans: *mut TsSpan,
+    pub len: usize,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_error(message: String) {
+    let message = CString::new(message.replace('\0', " ")).expect("NULs were replaced");
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(message));
+}
+
+/// Runs the body of an entry point. A panic must not unwind into C: it is
+/// turned into `on_panic`, with the panic message as the last error.
+fn guard<T>(on_panic: T, body: impl FnOnce() -> T) -> T {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or_else(|payload| {
+        let message = match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+            (Some(message), _) => message.to_string(),
+            (_, Some(message)) => message.clone(),
+            _ => String::from("unknown panic"),
+        };
+        set_error(format!("panic: {}", message));
+        on_panic
+    })
+}
+
+fn handle(model: Result<Model, String>) -> *mut TinySegmenter {
+    match model {
+        Ok(model) => Box::into_raw(Box::new(TinySegmenter { segmenter: Segmenter::with_model(model) })),
+        Err(message) => {
+            set_error(message);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// The version of the C API this library implements, `ABI_VERSION`.
+#[no_mangle]
+pub extern "C" fn tinysegmenter_abi_version() -> u32 {
+    guard(ABI_VERSION, || ABI_VERSION)
+}
+
+/// The message of the last error on this thread, or NULL. Valid until the
+/// next failing call on the same thread.
+#[no_mangle]
+pub extern "C" fn tinysegmenter_last_error() -> *const c_char {
+    guard(ptr::null(), || {
+        LAST_ERROR.with(|e| e.borrow().as_ref().map_or(ptr::null(), |m| m.as_ptr()))
+    })
+}
+
+/// A segmenter using the built-in model, or the small built-in model in
+/// builds with only the `small-model` feature. Returns NULL and sets the last
+/// error if the library was built with neither.
+#[no_mangle]
+pub extern "C" fn tinysegmenter_new() -> *mut TinySegmenter {
+    guard(ptr::null_mut(), || handle(builtin()))
+}
+
+#[cfg(feature = "builtin-model")]
+fn builtin() -> Result<Model, String> {
+    Ok(Model::builtin())
+}
+
+#[cfg(all(feature = "small-model", not(feature = "builtin-model")))]
+fn builtin() -> Result<Model, String> {
+    Ok(Model::builtin_small())
+}
+
+#[cfg(not(any(feature = "builtin-model", feature = "small-model")))]
+fn builtin() -> Result<Model, String> {
+    Err(String::from("built without a built-in model; load a model instead"))
+}
+
+/// A segmenter using the model file at `path`, native or quantized. Returns
+/// NULL and sets the last error on failure.
+///
+/// # Safety
+/// `path` must be NULL or a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn tinysegmenter_from_path(path: *const c_char) -> *mut TinySegmenter {
+    guard(ptr::null_mut(), || {
+        if path.is_null() {
+            return handle(Err(String::from("path is NULL")));
+        }
+        let path = CStr::from_ptr(path).to_string_lossy();
+        handle(fs::read_to_string(path.as_ref()).map_err(|e| format!("{}: {}", path, e)).and_then(|text| {
+            parse_any(&text).map_err(|e| format!("{}: {}", path, e))
+        }))
+    })
+}
+
+/// A segmenter using the model file contents `data[0..len]`, native or
+/// quantized. Returns NULL and sets the last error on failure.
+///
+/// # Safety
+/// `data` must point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn tinysegmenter_from_bytes(data: *const u8, len: usize) -> *mut TinySegmenter {
+    guard(ptr::null_mut(), || {
+        if data.is_null() {
+            return handle(Err(String::from("data is NULL")));
+        }
+        let text = std::str::from_utf8(slice::from_raw_parts(data, len)).map_err(|e| format!("model is not UTF-8: {}", e));
+        handle(text.and_then(|text| parse_any(text).map_err(|e| e.to_string())))
+    })
+}
+
+/// Frees a segmenter. NULL is ignored.
+///
+/// # Safety
+/// `segmenter` must be NULL or come from one of the constructors, and must
+/// not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn tinysegmenter_free(segmenter: *mut TinySegmenter) {
+    guard((), || {
+        if !segmenter.is_null() {
+            drop(Box::from_raw(segmenter));
+        }
+    })
+}
+
+/// Segments the UTF-8 text `text[0..len]` into `out`. Returns 0 on success;
+/// otherwise -1, with `out` emptied and the last error set.
+///
+/// # Safety
+/// `segmenter` must be a live handle, `text` must point to `len` readable
+/// bytes and `out` must be writable. A segmenter may be used from several
+/// threads at once.
+#[no_mangle]
+pub unsafe extern "C" fn tinysegmenter_segment(
+    segmenter: *const TinySegmenter,
+    text: *const c_char,
+    len: usize,
+    out: *mut TsSegments,
+) -> c_int {
+    guard(-1, || {
+        if out.is_null() {
+            set_error(String::from("out is NULL"));
+            return -1;
+        }
+        *out = TsSegments { spans: ptr::null_mut(), len: 0 };
+        if segmenter.is_null() || (text.is_null() && len > 0) {
+            set_error(String::from("segmenter or text is NULL"));
+            return -1;
+        }
+        let bytes = if len == 0 { &[][..] } else { slice::from_raw_parts(text as *const u8, len) };
+        let text = match std::str::from_utf8(bytes) {
+            Ok(text) => text,
+            Err(e) => {
+                set_error(format!("text is not UTF-8: {}", e));
+                return -1;
+            }
+        };
+
+        let mut spans = Vec::new();
+        let mut start = 0;
+        for word in (*segmenter).segmenter.segment(text) {
+            spans.push(TsSpan { start, end: start + word.len() });
+            start += word.len();
+        }
+        let spans = spans.into_boxed_slice();
+        let len = spans.len();
+        *out = TsSegments { spans: Box::into_raw(spans) as *mut TsSpan, len };
+        0
+    })
+}
+
+/// Frees the spans of `segments` and empties it. Empty results and NULL are
+/// ignored.
+///
+/// # Safety
+/// `segments` must be NULL or filled by `tinysegmenter_segment`.
+#[no_mangle]
+pub unsafe extern "C" fn tinysegmenter_segments_free(segments: *mut TsSegments) {
+    guard((), || {
+        if segments.is_null() || (*segments).spans.is_null() {
+            return;
+        }
+        let TsSegments { spans, len } = *segments;
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(spans, len)));
+        *segments = TsSegments { spans: ptr::null_mut(), len: 0 };
+    })
+}
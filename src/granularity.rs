@@ -0,0 +1,146 @@
+use std::str::FromStr;
+
+use crate::{predict_boundaries, Decision, Segmenter, Weights};
+
+/// A level of the unit hierarchy returned by `segment_units`, after
+/// Sudachi's A/B/C split modes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Granularity {
+    /// The segmenter's own words (A).
+    Short,
+    /// Adjacent words of the same script merged where the split between them
+    /// is weak (B).
+    Middle,
+    /// Runs of kanji and katakana words merged into compounds (C).
+    Long,
+}
+
+impl FromStr for Granularity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Granularity, String> {
+        match s {
+            "short" | "A" => Ok(Granularity::Short),
+            "middle" | "B" => Ok(Granularity::Middle),
+            "long" | "C" => Ok(Granularity::Long),
+            other => Err(format!("unknown granularity `{}`; expected short, middle or long", other)),
+        }
+    }
+}
+
+/// When a split between two words is weak enough to merge them into a
+/// compound. Margins are the gap's score less the threshold, so every split
+/// the segmenter makes has a positive margin under greedy decoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompoundConfig {
+    /// Middle units merge words written in the same script, all kanji or all
+    /// katakana, across splits with a margin below this.
+    pub middle_margin: i32,
+    /// Long units merge any kanji or katakana words across splits with a
+    /// margin below this, on top of the middle merges.
+    pub long_margin: i32,
+}
+
+impl Default for CompoundConfig {
+    fn default() -> Self {
+        CompoundConfig { middle_margin: 4000, long_margin: 8000 }
+    }
+}
+
+/// A unit of one granularity, spanning characters `start..end` of the text,
+/// with its units at the next finer granularity. Short units have no parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unit {
+    pub surface: String,
+    pub start: usize,
+    pub end: usize,
+    pub parts: Vec<Unit>,
+}
+
+impl Unit {
+    /// The surfaces of the units at `granularity` within `units`, which are
+    /// long units as `segment_units` returns them.
+    pub fn words(units: &[Unit], granularity: Granularity) -> Vec<String> {
+        match granularity {
+            Granularity::Long => units.iter().map(|u| u.surface.clone()).collect(),
+            Granularity::Middle => units.iter().flat_map(|u| &u.parts).map(|u| u.surface.clone()).collect(),
+            Granularity::Short => units.iter().flat_map(|u| &u.parts).flat_map(|u| &u.parts).map(|u| u.surface.clone()).collect(),
+        }
+    }
+}
+
+/// The script of a word for compounding, if it is all kanji or all katakana.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Script {
+    Kanji,
+    Katakana,
+}
+
+fn script(ctype: &[char]) -> Option<Script> {
+    if ctype.iter().all(|t| matches!(t, 'H' | 'M')) {
+        Some(Script::Kanji)
+    } else if ctype.iter().all(|t| *t == 'K') {
+        Some(Script::Katakana)
+    } else {
+        None
+    }
+}
+
+/// Groups `chars` into units at the gaps `split` marks, each holding the
+/// `parts` that fall inside it.
+fn group(chars: &[String], split: &[bool], mut parts: Vec<Unit>) -> Vec<Unit> {
+    let mut units = Vec::new();
+    let mut start = 0;
+    for end in 1..=chars.len() {
+        if end == chars.len() || split[end - 1] {
+            let rest = parts.split_off(parts.iter().take_while(|p| p.end <= end).count());
+            units.push(Unit { surface: chars[start..end].concat(), start, end, parts: std::mem::replace(&mut parts, rest) });
+            start = end;
+        }
+    }
+    units
+}
+
+impl<W: Weights> Segmenter<W> {
+    /// Segments `text` once and returns its long units, each holding its
+    /// middle units, each holding its short units: the words `segment`
+    /// returns. Weak splits between kanji or katakana words are merged as
+    /// `SegmenterConfig::compounds` describes.
+    pub fn segment_units(&self, text: &str) -> Vec<Unit> {
+        if text.is_empty() { return Vec::new(); }
+
+        let sentence = self.sentence(text, &self.config);
+        let mut margins = Vec::new();
+        let mut short = Vec::new();
+        let threshold = self.config.threshold as i64;
+        predict_boundaries(&sentence, &self.model, &self.config, |_, score, decision| {
+            margins.push(score as i64 - threshold);
+            short.push(decision == Decision::Boundary);
+        });
+
+        // The scripts of the short words on either side of every split.
+        let chars = &sentence.chars;
+        let short_units = group(chars, &short, Vec::new());
+        let mut scripts = vec![(None, None); short.len()];
+        for pair in short_units.windows(2) {
+            let (a, b) = (&pair[0], &pair[1]);
+            scripts[a.end - 1] = (script(&sentence.ctype[a.start..a.end]), script(&sentence.ctype[b.start..b.end]));
+        }
+
+        let compounds = &self.config.compounds;
+        let mut middle = short.clone();
+        let mut long = short;
+        for (i, (a, b)) in scripts.into_iter().enumerate() {
+            let (Some(a), Some(b)) = (a, b) else { continue };
+            if margins[i] < compounds.middle_margin as i64 && a == b {
+                middle[i] = false;
+                long[i] = false;
+            } else if margins[i] < compounds.long_margin as i64 {
+                long[i] = false;
+            }
+        }
+
+        let middle_units = group(chars, &middle, short_units);
+        group(chars, &long, middle_units)
+    }
+}
@@ -0,0 +1,77 @@
+use crate::model::Model;
+
+/// Size and weight range of one template table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TemplateStats {
+    pub name: String,
+    pub entries: usize,
+    pub min: Option<i32>,
+    pub max: Option<i32>,
+}
+
+/// A single weighted feature, written `TEMPLATE:feature` (e.g. `BW2:日本`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Feature {
+    pub template: String,
+    pub feature: String,
+    pub weight: i32,
+}
+
+impl Feature {
+    pub fn key(&self) -> String {
+        format!("{}:{}", self.template, self.feature)
+    }
+}
+
+/// Splits `BW2:日本` into `("BW2", "日本")`.
+pub fn parse_feature_key(key: &str) -> Option<(&str, &str)> {
+    key.split_once(':')
+}
+
+/// Stats for every template of the model, plus any extra tables it carries.
+/// Templates without a table are listed with zero entries.
+pub fn template_stats(model: &Model) -> Vec<TemplateStats> {
+    let mut names: Vec<String> = model.templates.iter().map(|t| t.name.clone()).collect();
+    for name in model.template_names() {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names.into_iter()
+        .map(|name| {
+            let table = model.tables.get(&name);
+            TemplateStats {
+                entries: table.map_or(0, |t| t.len()),
+                min: table.and_then(|t| t.values().min().copied()),
+                max: table.and_then(|t| t.values().max().copied()),
+                name,
+            }
+        })
+        .collect()
+}
+
+fn all_features(model: &Model) -> Vec<Feature> {
+    let mut features = Vec::new();
+    for (template, table) in &model.tables {
+        for (feature, weight) in table {
+            features.push(Feature { template: template.clone(), feature: feature.clone(), weight: *weight });
+        }
+    }
+    features
+}
+
+/// The `n` largest positive and `n` most negative weights across all templates.
+pub fn top_features(model: &Model, n: usize) -> (Vec<Feature>, Vec<Feature>) {
+    let mut features = all_features(model);
+    features.sort_by(|a, b| b.weight.cmp(&a.weight).then_with(|| a.key().cmp(&b.key())));
+    let positive = features.iter().filter(|f| f.weight > 0).take(n).cloned().collect();
+    features.sort_by(|a, b| a.weight.cmp(&b.weight).then_with(|| a.key().cmp(&b.key())));
+    let negative = features.iter().filter(|f| f.weight < 0).take(n).cloned().collect();
+    (positive, negative)
+}
+
+/// Looks up a `TEMPLATE:feature` key; `None` if the model has no such entry.
+pub fn lookup(model: &Model, key: &str) -> Option<i32> {
+    let (template, feature) = parse_feature_key(key)?;
+    model.tables.get(template)?.get(feature).copied()
+}
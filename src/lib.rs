@@ -0,0 +1,455 @@
+/*
+TinySegmenter 0.1 -- Super compact Japanese tokenizer in Javascript
+(c) 2008 Taku Kudo <taku@chasen.org>
+TinySegmenter is freely distributable under the terms of a new BSD licence.
+For details, see http://lilyx.net/pages/tinysegmenter_licence.txt
+**/
+
+/*
+TinySegmenter in Rust is written and distributed by Taichi Nishimura
+**/
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+pub mod analyzer;
+pub mod calibrate;
+pub mod compress;
+pub mod constraint;
+pub mod corpus;
+#[cfg(feature = "builtin-model")]
+mod default_model;
+pub mod dictionary;
+pub mod eval;
+pub mod explain;
+pub mod ffi;
+pub mod granularity;
+pub mod inspect;
+pub mod merge;
+pub mod model;
+pub mod nbest;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod select;
+#[cfg(feature = "small-model")]
+mod small_model;
+#[cfg(feature = "tantivy")]
+pub mod tantivy_tokenizer;
+pub mod template;
+pub mod train;
+mod viterbi;
+#[cfg(target_family = "wasm")]
+pub mod wasm;
+
+pub use analyzer::Analyzer;
+pub use explain::GapExplanation;
+pub use granularity::{CompoundConfig, Granularity, Unit};
+pub use compress::QuantizedModel;
+pub use constraint::RunGrouping;
+pub use dictionary::Dictionary;
+pub use model::{Model, ModelError, Weights};
+pub use nbest::{Lattice, Segmentation};
+pub use template::{Template, DEFAULT_TEMPLATES};
+
+use template::{Slot, Source};
+
+/// The decision taken at a gap, as seen by the history features.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Decision {
+    /// A gap before the start of the text (`U`).
+    Unknown,
+    /// A word boundary (`B`).
+    Boundary,
+    /// No boundary (`O`).
+    Inside,
+}
+
+impl Decision {
+    fn from_score(score: i32) -> Decision {
+        if score > 0 { Decision::Boundary } else { Decision::Inside }
+    }
+
+    fn tag(self) -> char {
+        match self {
+            Decision::Unknown => 'U',
+            Decision::Boundary => 'B',
+            Decision::Inside => 'O',
+        }
+    }
+}
+
+/// A text prepared for scoring: its characters, their types and, when a
+/// dictionary is in use, the dictionary features of each gap.
+struct Sentence {
+    chars: Vec<String>,
+    ctype: Vec<char>,
+    /// Indexed by the number of characters before the gap; empty without a
+    /// dictionary.
+    dictionary: Vec<Vec<String>>,
+    /// Decisions fixed before decoding, indexed like `history`; empty when
+    /// every gap is left to the model.
+    constraints: Vec<Option<Decision>>,
+}
+
+impl Sentence {
+    fn new(text: &str, char_map: &HashMap<char, char>, dictionary: Option<&Dictionary>) -> Sentence {
+        let chars = construct_words(text);
+        let str_chars: Vec<String> = chars.iter().map(|c| String::from(*c)).collect();
+        let ctype: Vec<char> = chars.into_iter().map(|c| get_key_tag(&c, char_map)).collect();
+        let dictionary = dictionary.map_or_else(Vec::new, |d| d.gap_features(&str_chars));
+        Sentence { chars: str_chars, ctype, dictionary, constraints: Vec::new() }
+    }
+
+    /// The decision fixed at the gap with `position` characters before it.
+    fn constraint(&self, position: usize) -> Option<Decision> {
+        self.constraints.get(position - 1).copied().flatten()
+    }
+}
+
+/// The context of one gap: the characters and character types around it and
+/// the decisions already taken at the gaps before it.
+struct Window<'a> {
+    sentence: &'a Sentence,
+    /// Number of characters before the gap.
+    position: usize,
+    history: &'a [Decision],
+}
+
+impl<'a> Window<'a> {
+    /// Index into `chars` of a character offset; negative past either end.
+    fn index(&self, offset: i32) -> isize {
+        let position = self.position as isize;
+        if offset < 0 { position + offset as isize } else { position + offset as isize - 1 }
+    }
+
+    fn dictionary_features(&self) -> &'a [String] {
+        self.sentence.dictionary.get(self.position).map_or(&[], |f| f.as_slice())
+    }
+
+    /// Appends what `slot` reads. Positions before the text read as the
+    /// sentinels `B1`, `B2`, ... and positions after it as `E1`, `E2`, ...,
+    /// both of type `O`.
+    fn push_slot(&self, slot: &Slot, out: &mut String) {
+        match slot.source {
+            Source::Decision => {
+                let back = slot.offset.unsigned_abs() as usize;
+                let decision = if back <= self.history.len() { self.history[self.history.len() - back] } else { Decision::Unknown };
+                out.push(decision.tag());
+            }
+            Source::Dictionary => {}
+            Source::Char | Source::Type => {
+                let index = self.index(slot.offset);
+                let len = self.sentence.chars.len() as isize;
+                if (0..len).contains(&index) {
+                    if slot.source == Source::Char {
+                        out.push_str(&self.sentence.chars[index as usize]);
+                    } else {
+                        out.push(self.sentence.ctype[index as usize]);
+                    }
+                } else if slot.source == Source::Type {
+                    out.push('O');
+                } else if index < 0 {
+                    out.push_str(&format!("B{}", -index));
+                } else {
+                    out.push_str(&format!("E{}", index - len + 1));
+                }
+            }
+        }
+    }
+}
+
+fn construct_words(text: &str) -> Vec<char> {
+    text.chars().collect()
+}
+
+fn construct_hashmap() -> HashMap<char, char> {
+    let char_patterns: Vec<((char, char), char)> = vec![
+        (('一', '龠'), 'H'),
+        (('ぁ', 'ん'), 'I'),
+        (('ァ', 'ヴ'),  'K'),
+        (('ｱ', 'ﾝ'), 'K'),
+        (('a', 'z'), 'A'),
+        (('A', 'Z'), 'A'),
+        (('ａ', 'ｚ'), 'A'),
+        (('Ａ', 'Ｚ'), 'A'),
+        (('0', '9'), 'N'),
+        (('０', '９'), 'N')
+    ];
+
+    let mut char_map = HashMap::new();
+    for ((c_st, c_ed), tag_type) in char_patterns {
+        let start : u32 = c_st.into();
+        let end : u32 = c_ed.into();
+        for c in start..=end {
+            let chr : char = char::from_u32(c).unwrap();
+            char_map.insert(chr, tag_type);
+        }
+    }
+    
+    let other_chars = vec![
+        ("一二三四五六七八九十百千万億兆", 'M'),
+        ("々〆ヵヶ", 'H'),
+        ("ーｰ\u{ff9e}", 'K')
+    ];
+    for (chars, tag_type) in other_chars {
+        for c in chars.chars() {
+            char_map.insert(c, tag_type);
+        }
+    }
+
+    char_map
+}
+
+fn get_key_tag(c: &char, char_map: &HashMap<char, char>) -> char {
+    match char_map.get(c) {
+        Some(x) => *x,
+        None => 'O'
+    }
+}
+
+fn get_score<W: Weights>(key: &str, word: &str, model: &W) -> i32 {
+    model.table_weight(key, word).unwrap_or(0)
+}
+
+/// Every `(template, feature)` pair `compute_score` looks up for one gap.
+fn extract_features<'t>(templates: &'t [Template], window: &Window) -> Vec<(&'t str, String)> {
+    let mut features = Vec::with_capacity(templates.len());
+    for template in templates {
+        if template.is_dictionary() {
+            features.extend(window.dictionary_features().iter().map(|f| (template.name.as_str(), f.clone())));
+            continue;
+        }
+        let mut feature = String::new();
+        for slot in &template.slots {
+            window.push_slot(slot, &mut feature);
+        }
+        features.push((template.name.as_str(), feature));
+    }
+    features
+}
+
+fn compute_score<W: Weights>(window: &Window, model: &W, bias: i32) -> i32 {
+    let mut score = bias;
+    for (template, feature) in extract_features(model.templates(), window) {
+        score = score.saturating_add(get_score(template, &feature, model));
+    }
+    score
+}
+
+fn boundary_prediction<W: Weights>(sentence: &Sentence, model: &W, config: &SegmenterConfig) -> Vec<String> {
+    predict_boundaries(sentence, model, config, |_, _, _| {})
+}
+
+/// Runs the decoder chosen by `config`, calling `on_gap` with each gap's
+/// window, score and decision. Gaps fixed by the sentence's constraints keep
+/// their decision whatever their score.
+fn predict_boundaries<W, F>(sentence: &Sentence, model: &W, config: &SegmenterConfig, mut on_gap: F) -> Vec<String>
+where
+    W: Weights,
+    F: FnMut(&Window, i32, Decision),
+{
+    let bias = config.bias(model);
+    let path = match config.decode {
+        DecodeMode::Greedy => None,
+        DecodeMode::Viterbi => Some(viterbi::best_path(sentence, model, config)),
+    };
+    walk_gaps(sentence, |window| {
+        let score = compute_score(window, model, bias);
+        let decision = match &path {
+            Some(path) => path[window.position - 1],
+            None => sentence.constraint(window.position).unwrap_or_else(|| config.decide(score)),
+        };
+        on_gap(window, score, decision);
+        decision
+    })
+}
+
+/// Visits the gaps of `sentence` left to right, asks `decide` for the
+/// decision at each and returns the words those decisions produce. The history
+/// features see exactly these decisions.
+fn walk_gaps<F>(sentence: &Sentence, mut decide: F) -> Vec<String>
+where
+    F: FnMut(&Window) -> Decision,
+{
+    let chars = &sentence.chars;
+    let mut result: Vec<String> = Vec::new();
+    let mut history: Vec<Decision> = Vec::with_capacity(chars.len());
+    let mut word = chars[0].clone();
+
+    for (position, c) in chars.iter().enumerate().skip(1) {
+        let window = Window { sentence, position, history: &history };
+        // segment or not?
+        let decision = decide(&window);
+        if decision == Decision::Boundary {
+            result.push(word);
+            word = string("");
+        }
+
+        history.push(decision);
+        word.push_str(c);
+    }
+
+    result.push(word);
+    result
+}
+
+fn string(text: &str) -> String {
+    String::from(text)
+}
+
+/// How the decisions at the gaps are chosen.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeMode {
+    /// Left to right, each gap split when its score exceeds the threshold,
+    /// as the reference TinySegmenter does.
+    #[default]
+    Greedy,
+    /// The decisions maximising the sum of the margins over the threshold of
+    /// all gaps, counted positively at boundaries and negatively elsewhere,
+    /// so that an early decision can be revised for the sake of later ones.
+    Viterbi,
+}
+
+/// Decoding options applied on top of a model's weights.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SegmenterConfig {
+    /// A gap is a boundary when its score exceeds the threshold. Positive
+    /// values give coarser segmentation, negative values finer.
+    pub threshold: i32,
+    /// Replaces the model's bias when set.
+    pub bias: Option<i32>,
+    pub decode: DecodeMode,
+    /// How `segment_units` builds compounds.
+    pub compounds: CompoundConfig,
+    /// Runs of one character type kept as single words whatever the scores.
+    pub runs: RunGrouping,
+}
+
+impl SegmenterConfig {
+    /// The bias the scores of `model` start from.
+    pub fn bias<W: Weights>(&self, model: &W) -> i32 {
+        self.bias.unwrap_or_else(|| model.bias())
+    }
+
+    fn decide(&self, score: i32) -> Decision {
+        Decision::from_score(score.saturating_sub(self.threshold))
+    }
+}
+
+/// Segments text with a fixed character-type table and a boundary model.
+/// The model is a full `Model` by default, or any other `Weights` such as a
+/// `QuantizedModel`.
+pub struct Segmenter<W = Model> {
+    char_map: HashMap<char, char>,
+    model: W,
+    dictionary: Option<Dictionary>,
+    config: SegmenterConfig,
+}
+
+#[cfg(feature = "builtin-model")]
+impl Default for Segmenter {
+    fn default() -> Self {
+        Segmenter::new()
+    }
+}
+
+#[cfg(feature = "builtin-model")]
+impl Segmenter {
+    /// A segmenter using the built-in model.
+    pub fn new() -> Segmenter {
+        Segmenter::with_model(Model::builtin())
+    }
+}
+
+impl<W: Weights> Segmenter<W> {
+    pub fn with_model(model: W) -> Segmenter<W> {
+        Segmenter { char_map: construct_hashmap(), model, dictionary: None, config: SegmenterConfig::default() }
+    }
+
+    /// Supplies the word list for the model's dictionary templates. Without
+    /// one, dictionary templates contribute nothing.
+    pub fn with_dictionary(mut self, dictionary: Dictionary) -> Segmenter<W> {
+        self.dictionary = Some(dictionary);
+        self
+    }
+
+    pub fn with_config(mut self, config: SegmenterConfig) -> Segmenter<W> {
+        self.config = config;
+        self
+    }
+
+    pub fn model(&self) -> &W {
+        &self.model
+    }
+
+    pub fn config(&self) -> &SegmenterConfig {
+        &self.config
+    }
+
+    pub fn segment(&self, text: &str) -> Vec<String> {
+        self.segment_with(text, &self.config)
+    }
+
+    /// The words of `text`, each with its margin: the smallest distance to the
+    /// threshold among the gaps that delimit it or lie inside it, that is how
+    /// close the word came to being split or joined to a neighbour. Margins
+    /// come from the decoding pass that produced the words; gaps fixed by run
+    /// grouping do not count, and a word without other gaps has `i32::MAX`.
+    pub fn segment_with_margins(&self, text: &str) -> Vec<(String, i32)> {
+        if text.is_empty() { return Vec::new(); }
+
+        let mut margins = Vec::new();
+        let words = predict_boundaries(&self.sentence(text, &self.config), &self.model, &self.config, |window, score, _| {
+            let forced = window.sentence.constraint(window.position).is_some();
+            margins.push(if forced { i32::MAX } else { score.saturating_sub(self.config.threshold).saturating_abs() });
+        });
+        let mut start = 0;
+        words.into_iter()
+            .map(|word| {
+                let end = start + word.chars().count();
+                // `margins[i]` is the gap after character `i`.
+                let margin = margins[start.saturating_sub(1)..end.min(margins.len())].iter().copied().min().unwrap_or(i32::MAX);
+                start = end;
+                (word, margin)
+            })
+            .collect()
+    }
+
+    /// The words of `text` with their byte ranges in it. Each
+    /// whitespace-separated run is segmented on its own, so whitespace is
+    /// never part of a word.
+    pub(crate) fn segment_spans(&self, text: &str) -> Vec<(String, Range<usize>)> {
+        let mut spans = Vec::new();
+        let mut offset = 0;
+        for chunk in text.split_inclusive(char::is_whitespace) {
+            let run = chunk.trim_end_matches(char::is_whitespace);
+            for word in self.segment(run) {
+                let end = offset + word.len();
+                spans.push((word, offset..end));
+                offset = end;
+            }
+            offset += chunk.len() - run.len();
+        }
+        spans
+    }
+
+    /// Segments `text` with `config` in place of the segmenter's own.
+    pub fn segment_with(&self, text: &str, config: &SegmenterConfig) -> Vec<String> {
+        if text.is_empty() { return Vec::new(); }
+
+        boundary_prediction(&self.sentence(text, config), &self.model, config)
+    }
+
+    /// `text` prepared for decoding under `config`.
+    fn sentence(&self, text: &str, config: &SegmenterConfig) -> Sentence {
+        let mut sentence = Sentence::new(text, &self.char_map, self.dictionary.as_ref());
+        sentence.constraints = config.runs.constraints(&sentence.chars, &sentence.ctype);
+        sentence
+    }
+}
+
+/// Segments `text` with the built-in model and joins the words with spaces.
+#[cfg(feature = "builtin-model")]
+pub fn tokenize(text: &str) -> String {
+    Segmenter::new().segment(text).join(" ")
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use crate::eval::{evaluate, BoundaryScores};
+use crate::model::{Model, ModelError, Weights};
+use crate::template::Template;
+use crate::{Dictionary, Segmenter, SegmenterConfig};
+
+/// How the weights of a base model and an in-domain model are combined.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Combination {
+    /// `(1 - λ) * base + λ * domain`, with `λ` taken from `templates` where
+    /// given and `lambda` otherwise (also for the bias).
+    Interpolate { lambda: f64, templates: HashMap<String, f64> },
+    /// `base + domain`: the in-domain model is a correction on top of the base.
+    Stack,
+}
+
+impl Combination {
+    /// Interpolation with the same `lambda` for every template.
+    pub fn interpolate(lambda: f64) -> Combination {
+        Combination::Interpolate { lambda, templates: HashMap::new() }
+    }
+
+    fn combine(&self, template: Option<&str>, base: i32, domain: i32) -> i32 {
+        match self {
+            Combination::Interpolate { lambda, templates } => {
+                let lambda = template.and_then(|t| templates.get(t)).unwrap_or(lambda);
+                ((1.0 - lambda) * base as f64 + lambda * domain as f64).round() as i32
+            }
+            Combination::Stack => base.saturating_add(domain),
+        }
+    }
+}
+
+/// The templates of `base` followed by those only `domain` has.
+fn union_templates(base: &[Template], domain: &[Template]) -> Result<Vec<Template>, ModelError> {
+    let mut templates = base.to_vec();
+    for template in domain {
+        match base.iter().find(|t| t.name == template.name) {
+            Some(t) if t != template => return Err(ModelError::TemplateMismatch(template.name.clone())),
+            Some(_) => {}
+            None => templates.push(template.clone()),
+        }
+    }
+    Ok(templates)
+}
+
+/// Two models scored as one, combining their weights at lookup time.
+pub struct Combined<A, B> {
+    base: A,
+    domain: B,
+    combination: Combination,
+    templates: Vec<Template>,
+}
+
+impl<A: Weights, B: Weights> Combined<A, B> {
+    /// Fails if the models define a template of the same name differently.
+    pub fn new(base: A, domain: B, combination: Combination) -> Result<Combined<A, B>, ModelError> {
+        let templates = union_templates(base.templates(), domain.templates())?;
+        Ok(Combined { base, domain, combination, templates })
+    }
+}
+
+impl<A: Weights, B: Weights> Weights for Combined<A, B> {
+    fn templates(&self) -> &[Template] {
+        &self.templates
+    }
+
+    fn bias(&self) -> i32 {
+        self.combination.combine(None, self.base.bias(), self.domain.bias())
+    }
+
+    fn table_weight(&self, template: &str, feature: &str) -> Option<i32> {
+        match (self.base.table_weight(template, feature), self.domain.table_weight(template, feature)) {
+            (None, None) => None,
+            (base, domain) => Some(self.combination.combine(Some(template), base.unwrap_or(0), domain.unwrap_or(0))),
+        }
+    }
+}
+
+/// Combines two models into one, feature by feature. Features only one model
+/// has count as 0 in the other; features combining to 0 are dropped.
+pub fn merge(base: &Model, domain: &Model, combination: &Combination) -> Result<Model, ModelError> {
+    let templates = union_templates(&base.templates, &domain.templates)?;
+    let empty = HashMap::new();
+    let mut names: Vec<&String> = base.tables.keys().chain(domain.tables.keys()).collect();
+    names.sort();
+    names.dedup();
+    let tables = names.into_iter()
+        .map(|name| {
+            let (b, d) = (base.tables.get(name).unwrap_or(&empty), domain.tables.get(name).unwrap_or(&empty));
+            let table: HashMap<String, i32> = b.keys().chain(d.keys())
+                .map(|feature| {
+                    let w = |t: &HashMap<String, i32>| t.get(feature).copied().unwrap_or(0);
+                    (feature.clone(), combination.combine(Some(name), w(b), w(d)))
+                })
+                .filter(|(_, w)| *w != 0)
+                .collect();
+            (name.clone(), table)
+        })
+        .collect();
+    let bias = combination.combine(None, base.bias, domain.bias);
+    Ok(Model { templates, bias, tables })
+}
+
+/// Evaluates uniform interpolation at every `λ` of `lambdas` on `dev`,
+/// segmenting with `config` as the merged model will be.
+pub fn sweep_lambda(
+    base: &Model,
+    domain: &Model,
+    dictionary: Option<&Dictionary>,
+    config: &SegmenterConfig,
+    dev: &[Vec<String>],
+    lambdas: &[f64],
+) -> Result<Vec<(f64, BoundaryScores)>, ModelError> {
+    lambdas.iter()
+        .map(|&lambda| {
+            let combined = Combined::new(base, domain, Combination::interpolate(lambda))?;
+            let mut segmenter = Segmenter::with_model(combined).with_config(*config);
+            if let Some(dictionary) = dictionary {
+                segmenter = segmenter.with_dictionary(dictionary.clone());
+            }
+            Ok((lambda, evaluate(&segmenter, dev)))
+        })
+        .collect()
+}
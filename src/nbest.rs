@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use crate::{compute_score, predict_boundaries, walk_gaps, Decision, Segmenter, Weights, Window};
+
+/// A segmentation proposed by `segment_nbest`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segmentation {
+    pub words: Vec<String>,
+    /// The sum over all gaps of the gap's margin over the threshold, counted
+    /// positively where the gap is split and negatively where it is not.
+    pub score: i64,
+}
+
+/// A partial segmentation: the decisions taken so far and their score.
+struct Hypothesis {
+    history: Vec<Decision>,
+    score: i64,
+}
+
+impl<W: Weights> Segmenter<W> {
+    /// Up to `n` segmentations of `text`, best first, found by a beam search
+    /// of width `n` over the boundary decisions. Each hypothesis keeps its own
+    /// history, so the history features of every gap see the decisions of the
+    /// segmentation being scored. With `n = 1` the configured decoder runs
+    /// instead, so the result is exactly `segment`, Viterbi decoding included.
+    pub fn segment_nbest(&self, text: &str, n: usize) -> Vec<Segmentation> {
+        if text.is_empty() || n == 0 { return Vec::new(); }
+
+        let sentence = self.sentence(text, &self.config);
+        if n == 1 {
+            let mut score = 0;
+            let words = predict_boundaries(&sentence, &self.model, &self.config, |_, gap, decision| {
+                let margin = gap as i64 - self.config.threshold as i64;
+                score += if decision == Decision::Boundary { margin } else { -margin };
+            });
+            return vec![Segmentation { words, score }];
+        }
+
+        let bias = self.config.bias(&self.model);
+        let mut beam = vec![Hypothesis { history: Vec::new(), score: 0 }];
+        for position in 1..sentence.chars.len() {
+            let mut next = Vec::with_capacity(beam.len() * 2);
+            for hypothesis in beam {
+                let window = Window { sentence: &sentence, position, history: &hypothesis.history };
+                let margin = compute_score(&window, &self.model, bias) as i64 - self.config.threshold as i64;
+                // Inside first: the sort is stable and ties are not splits,
+                // as in `segment`.
+                for (decision, score) in [(Decision::Inside, -margin), (Decision::Boundary, margin)] {
+                    if sentence.constraint(position).is_some_and(|forced| forced != decision) {
+                        continue;
+                    }
+                    let mut history = hypothesis.history.clone();
+                    history.push(decision);
+                    next.push(Hypothesis { history, score: hypothesis.score + score });
+                }
+            }
+            next.sort_by_key(|h| std::cmp::Reverse(h.score));
+            next.truncate(n);
+            beam = next;
+        }
+
+        beam.into_iter()
+            .map(|h| Segmentation {
+                words: walk_gaps(&sentence, |window| h.history[window.position - 1]),
+                score: h.score,
+            })
+            .collect()
+    }
+
+    /// The words of the `n` best segmentations of `text` as a lattice.
+    pub fn lattice(&self, text: &str, n: usize) -> Lattice {
+        Lattice::from_nbest(&self.segment_nbest(text, n))
+    }
+}
+
+/// One word of a lattice, spanning characters `start..end` of the text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LatticeWord {
+    pub start: usize,
+    pub end: usize,
+    pub surface: String,
+    /// The score of the best segmentation containing the word.
+    pub score: i64,
+}
+
+/// Every distinct word of a set of segmentations of one text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lattice {
+    /// Ordered by `start`, then `end`.
+    pub words: Vec<LatticeWord>,
+}
+
+impl Lattice {
+    pub fn from_nbest(segmentations: &[Segmentation]) -> Lattice {
+        let mut best: HashMap<(usize, usize), LatticeWord> = HashMap::new();
+        for segmentation in segmentations {
+            let mut start = 0;
+            for word in &segmentation.words {
+                let end = start + word.chars().count();
+                let entry = best.entry((start, end)).or_insert_with(|| {
+                    LatticeWord { start, end, surface: word.clone(), score: segmentation.score }
+                });
+                entry.score = entry.score.max(segmentation.score);
+                start = end;
+            }
+        }
+        let mut words: Vec<LatticeWord> = best.into_values().collect();
+        words.sort_by_key(|w| (w.start, w.end));
+        Lattice { words }
+    }
+
+    /// One `start<TAB>end<TAB>surface<TAB>score` line per word.
+    pub fn to_tsv(&self) -> String {
+        self.words.iter().map(|w| format!("{}\t{}\t{}\t{}\n", w.start, w.end, w.surface, w.score)).collect()
+    }
+}
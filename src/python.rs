@@ -0,0 +1,77 @@
+use std::fs;
+use std::path::PathBuf;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::compress::parse_any;
+use crate::{Model, Segmenter};
+
+/// A drop-in replacement for the `TinySegmenter` class of the `tinysegmenter`
+/// package. Offsets are character offsets, as Python indexes strings.
+#[pyclass(frozen, module = "tinysegmenter")]
+pub struct TinySegmenter {
+    segmenter: Segmenter<Model>,
+}
+
+#[pymethods]
+impl TinySegmenter {
+    /// A segmenter using the built-in model, or the model file at `model`,
+    /// native or quantized.
+    #[new]
+    #[pyo3(signature = (model = None))]
+    fn new(model: Option<PathBuf>) -> PyResult<TinySegmenter> {
+        let model = match model {
+            None => Model::builtin(),
+            Some(path) => {
+                let text = fs::read_to_string(&path)?;
+                parse_any(&text).map_err(|e| PyValueError::new_err(format!("{}: {}", path.display(), e)))?
+            }
+        };
+        Ok(TinySegmenter { segmenter: Segmenter::with_model(model) })
+    }
+
+    /// The words of `text`.
+    fn tokenize(&self, text: &str) -> Vec<String> {
+        self.segmenter.segment(text)
+    }
+
+    /// The words of `text` as `(word, start, end)`, with `text[start:end] == word`.
+    fn tokenize_with_offsets(&self, text: &str) -> Vec<(String, usize, usize)> {
+        let mut start = 0;
+        self.segmenter
+            .segment(text)
+            .into_iter()
+            .map(|word| {
+                let end = start + word.chars().count();
+                let span = (word, start, end);
+                start = end;
+                span
+            })
+            .collect()
+    }
+
+    /// The words of every text, segmented without holding the GIL.
+    fn tokenize_batch(&self, py: Python<'_>, texts: Vec<String>) -> Vec<Vec<String>> {
+        py.allow_threads(|| texts.iter().map(|text| self.segmenter.segment(text)).collect())
+    }
+
+    /// Every gap of `text` as `(score, boundary)`: the score less the
+    /// threshold and whether `tokenize` split there.
+    fn gap_scores(&self, text: &str) -> Vec<(i32, bool)> {
+        self.segmenter.scored_gaps(text).into_iter().map(|gap| (gap.score, gap.boundary)).collect()
+    }
+
+    /// The words of `text` as `(word, margin)`, the margin being the smallest
+    /// gap score, in absolute value, that delimits the word or lies inside it.
+    fn tokenize_with_confidence(&self, text: &str) -> Vec<(String, i32)> {
+        self.segmenter.segment_with_margins(text)
+    }
+}
+
+#[pymodule]
+fn tinysegmenter(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<TinySegmenter>()?;
+    module.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use crate::corpus::{Annotation, Gap};
+use crate::{predict_boundaries, Decision, Segmenter, Weights};
+
+/// One gap of a segmented text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScoredGap {
+    /// The gap's score less the configured threshold.
+    pub score: i32,
+    /// Whether `segment` split here. Viterbi decoding and run grouping can
+    /// decide against the sign of `score`.
+    pub boundary: bool,
+    /// Whether run grouping fixed the decision whatever the score.
+    pub forced: bool,
+}
+
+impl<W: Weights> Segmenter<W> {
+    /// Every gap of `text`, in order, with its score and the decision
+    /// `segment` took there.
+    pub fn scored_gaps(&self, text: &str) -> Vec<ScoredGap> {
+        if text.is_empty() { return Vec::new(); }
+
+        let mut gaps = Vec::new();
+        let threshold = self.config.threshold;
+        predict_boundaries(&self.sentence(text, &self.config), &self.model, &self.config, |window, score, decision| {
+            gaps.push(ScoredGap {
+                score: score.saturating_sub(threshold),
+                boundary: decision == Decision::Boundary,
+                forced: window.sentence.constraint(window.position).is_some(),
+            });
+        });
+        gaps
+    }
+
+    /// The score of every gap of `text`, in order, as `segment` computes them,
+    /// less the configured threshold. With greedy decoding and no run grouping
+    /// positive scores are boundaries; otherwise see `scored_gaps`.
+    pub fn gap_scores(&self, text: &str) -> Vec<i32> {
+        self.scored_gaps(text).into_iter().map(|gap| gap.score).collect()
+    }
+}
+
+/// A gap proposed for annotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Candidate {
+    /// Index of the sentence in the corpus.
+    pub sentence: usize,
+    /// Number of characters before the gap.
+    pub position: usize,
+    pub score: i32,
+    /// The decision `primary` took.
+    pub boundary: bool,
+    /// Whether a second model decided this gap the other way.
+    pub disagreement: bool,
+}
+
+/// Ranks every gap, most uncertain first: gaps on which `primary` and
+/// `secondary` decided differently, then gaps with the smallest absolute
+/// score under `primary`. Both hold the scored gaps of each sentence of the
+/// same corpus. Gaps `primary` was forced to decide are left out: annotating
+/// them would not change its output.
+pub fn rank_gaps(primary: &[Vec<ScoredGap>], secondary: Option<&[Vec<ScoredGap>]>) -> Vec<Candidate> {
+    let mut candidates = Vec::new();
+    for (sentence, gaps) in primary.iter().enumerate() {
+        for (i, gap) in gaps.iter().enumerate().filter(|(_, gap)| !gap.forced) {
+            let disagreement = secondary.is_some_and(|other| other[sentence][i].boundary != gap.boundary);
+            candidates.push(Candidate { sentence, position: i + 1, score: gap.score, boundary: gap.boundary, disagreement });
+        }
+    }
+    candidates.sort_by_key(|c| (!c.disagreement, c.score.abs(), c.sentence, c.position));
+    candidates
+}
+
+fn guess(boundary: bool) -> Gap {
+    if boundary { Gap::Boundary } else { Gap::Inside }
+}
+
+/// The sentences containing `chosen`, with only the chosen gaps marked by
+/// `primary`'s guess, ordered by their most uncertain chosen gap.
+pub fn annotate_gaps(texts: &[String], primary: &[Vec<ScoredGap>], chosen: &[Candidate]) -> Vec<Annotation> {
+    let mut order: Vec<usize> = Vec::new();
+    let mut annotations: Vec<Annotation> = Vec::new();
+    for c in chosen {
+        let index = match order.iter().position(|s| *s == c.sentence) {
+            Some(index) => index,
+            None => {
+                order.push(c.sentence);
+                let gaps = vec![Gap::Unknown; primary[c.sentence].len()];
+                annotations.push(Annotation { text: texts[c.sentence].clone(), gaps });
+                annotations.len() - 1
+            }
+        };
+        annotations[index].gaps[c.position - 1] = guess(c.boundary);
+    }
+    annotations
+}
+
+/// The first `budget` distinct sentences of the ranking, with every gap
+/// marked by `primary`'s guess for the annotators to correct.
+pub fn annotate_sentences(texts: &[String], primary: &[Vec<ScoredGap>], ranked: &[Candidate], budget: usize) -> Vec<Annotation> {
+    let mut order: Vec<usize> = Vec::new();
+    for c in ranked {
+        if order.len() == budget { break; }
+        if !order.contains(&c.sentence) {
+            order.push(c.sentence);
+        }
+    }
+    order.into_iter()
+        .map(|s| Annotation { text: texts[s].clone(), gaps: primary[s].iter().map(|gap| guess(gap.boundary)).collect() })
+        .collect()
+}
@@ -0,0 +1,93 @@
+// @generated by tinysegmenter-codegen from models/default.q8.model. Do not edit.
+
+pub static TEMPLATES: &[(&str, &str)] = &[
+    ("UP1", "P-3"),
+    ("UP2", "P-2"),
+    ("UP3", "P-1"),
+    ("BP1", "P-3 P-2"),
+    ("BP2", "P-2 P-1"),
+    ("UW1", "W-3"),
+    ("UW2", "W-2"),
+    ("UW3", "W-1"),
+    ("UW4", "W1"),
+    ("UW5", "W2"),
+    ("UW6", "W3"),
+    ("BW1", "W-2 W-1"),
+    ("BW2", "W-1 W1"),
+    ("BW3", "W1 W2"),
+    ("TW1", "W-3 W-2 W-1"),
+    ("TW2", "W-2 W-1 W1"),
+    ("TW3", "W-1 W1 W2"),
+    ("TW4", "W1 W2 W3"),
+    ("UC1", "C-3"),
+    ("UC2", "C-2"),
+    ("UC3", "C-1"),
+    ("UC4", "C1"),
+    ("UC5", "C2"),
+    ("UC6", "C3"),
+    ("BC1", "C-2 C-1"),
+    ("BC2", "C-1 C1"),
+    ("BC3", "C1 C2"),
+    ("TC1", "C-3 C-2 C-1"),
+    ("TC2", "C-2 C-1 C1"),
+    ("TC3", "C-1 C1 C2"),
+    ("TC4", "C1 C2 C3"),
+    ("UQ1", "P-3 C-3"),
+    ("UQ2", "P-2 C-2"),
+    ("UQ3", "P-1 C-1"),
+    ("BQ1", "P-2 C-2 C-1"),
+    ("BQ2", "P-2 C-1 C1"),
+    ("BQ3", "P-1 C-2 C-1"),
+    ("BQ4", "P-1 C-1 C1"),
+    ("TQ1", "P-2 C-3 C-2 C-1"),
+    ("TQ2", "P-2 C-2 C-1 C1"),
+    ("TQ3", "P-1 C-3 C-2 C-1"),
+    ("TQ4", "P-1 C-2 C-1 C1"),
+];
+
+pub const BIAS: i32 = -332;
+
+pub static TABLES: &[(&str, f32, &str, &[i8])] = &[
+    ("BC1", 19.377953, "II\tKH\tOH", &[127, 21, -71]),
+    ("BC2", 68.826775, "AA\tAI\tAN\tHH\tHM\tHN\tHO\tIA\tIH\tII\tIK\tIO\tKI\tKK\tMH\tMK\tOO", &[-47, 40, -13, -59, -25, 58, 55, 19, -17, -19, 25, 80, 56, -127, -46, 48, -42]),
+    ("BC3", 31.763779, "HH\tHI\tHK\tHN\tHO\tIH\tKK\tMK\tMM\tOA\tOH", &[31, 20, -23, -41, -26, -9, 87, 34, 127, -52, 8]),
+    ("BP1", 2.7716537, "BB\tOB\tOO\tUB", &[106, 110, -45, 127]),
+    ("BP2", 13.874016, "BO\tOO", &[4, -127]),
+    ("BQ1", 23.346457, "BHH\tBHM\tBII\tBIM\tBMH\tBNH\tBOH\tBOO\tOHI\tOIH\tOKA\tOKH\tOKK\tOOO", &[49, 65, -50, 38, 52, 19, -4, -111, 19, -13, 79, -44, 39, 127]),
+    ("BQ2", 13.543307, "BHH\tBHI\tBHM\tBIH\tBKK\tBKO\tOHH\tOHM\tOIH\tUHI", &[9, -86, 34, -68, -127, 64, -84, -13, 11, -85]),
+    ("BQ3", 92.11811, "BHH\tBHI\tBII\tBKI\tBMH\tBMM\tBNN\tBOH\tOHH\tOHM\tOII\tOKH\tOKI\tOKO\tOMH\tOOO", &[-9, 29, -3, 5, 10, 90, 11, 8, 24, 5, 3, 20, -9, -24, -26, 127]),
+    ("BQ4", 97.6063, "BHH\tBIH\tBII\tBIK\tBKK\tBMI\tBOO\tOAH\tOHH\tOHK\tONN", &[-40, 39, -48, 14, -19, -35, -127, 9, 3, -21, -10]),
+    ("BW1", 58.102364, ",と\t,同\tB1あ\tB1同\t、と\t、同\t」と\tあっ\tいう\tいっ\tいる\tうし\tうん\tから\tがら\tこう\tこと\tこん\tさら\tさん\tした\tして\tすで\tそこ\tそれ\tたち\tため\tった\tつい\tてい\tてき\tでき\tです\tでは\tとい\tとみ\tどこ\tない\tなっ\tなど\tなん\tにし\tには\tにも\tに対\tの一\tの中\tませ\tまで\tまま\tまる\tやむ\tよっ\tれた\tれで\tをし\tを見\t亡く\t京都\t取り\t大き\t大阪\t平方\t引き\t日本\t本当\t毎日\t目指\tＢ１あ\tＢ１同\t｣と", &[11, 13, 24, 9, 11, 13, 29, 26, 30, -35, 12, -83, 11, 60, 10, -14, 36, -22, -71, 79, 45, 19, -59, 34, -15, 19, 10, 60, -14, 14, 21, 19, 59, 15, -85, 33, 67, 98, 52, 127, -19, 42, 26, 29, -16, -9, 13, 42, 29, 45, -37, -34, -44, 41, -16, 32, 13, -32, 44, -48, -45, 26, -40, -23, -3, -42, -36, -12, 24, 9, 29]),
+    ("BW2", 117.661415, "..\t11\t――\t−−\tいう\tうか\tかし\tかも\tから\tかれ\tがい\tがら\tきた\tくな\tこと\tこの\tさせ\tされ\tさん\tしい\tしか\tした\tして\tしな\tその\tたい\tたた\tただ\tたち\tたと\tたは\tった\tって\tっと\tてい\tてき\tてく\tては\tても\tでい\tでき\tでし\tです\tでも\tとい\tとこ\tとと\tとの\tとみ\tとも\tない\tなが\tなど\tなの\tなん\tにお\tにし\tにな\tによ\tに対\tに従\tに関\tのか\tので\tのに\tのの\tはい\tはが\tはず\tばれ\tまし\tまで\tまれ\tめて\tもい\tもの\tらか\tらし\tらに\tりし\tりま\tれた\tれて\tれば\tろう\tわれ\tを通\tんだ\tんな\t一人\t一方\t一日\t一部\t上が\t会社\t出て\t分の\t同党\t同日\t大阪\t委員\t少な\t年度\t年間\t府県\t手権\t新聞\t日新\t日本\t日米\t曜日\t朝鮮\t本人\t東京\t然と\t社会\t立て\t第に\t米国\t１１\tｸ\u{ff9e}", &[-100, -6, -49, -112, -14, 21, -11, -5, -61, 39, 7, -27, 16, -14, -71, -36, 39, 112, -34, -15, -5, 43, 8, 8, -32, -11, -6, -33, -7, 10, -8, 39, 14, -18, 52, 31, 22, -26, -26, 23, -13, -33, -40, -36, 16, -15, -19, 6, 44, -33, -21, -11, -55, 22, 26, -14, 23, 21, -61, -127, -40, -97, 18, -60, -51, -52, 9, -9, -22, 15, -11, -56, 46, -27, 19, -91, -8, -14, -16, 6, 14, 36, 7, 35, 52, 67, -101, 6, -35, 5, -12, 8, -9, -38, -9, 18, -66, 8, -8, -21, -11, -9, -74, -14, -20, -17, -35, -6, -60, 29, -5, -20, -23, -13, -12, -11, -8, -14, -36, -6, 11]),
+    ("BW3", 69.88189, "あた\tあり\tある\tい.\tい。\tいい\tいえ\tいく\tいた\tいっ\tいる\tいわ\tうち\tうと\tえと\tか.\tか。\tかけ\tかっ\tかに\tから\tかり\tが,\tが、\tがき\tがけ\tがっ\tがら\tがり\tきた\tけど\tこと\tこの\tころ\tさい\tさを\tし,\tし、\tしい\tした\tして\tしな\tしま\tす.\tす。\tする\tず,\tず、\tずに\tそう\tた.\tた。\tたい\tたの\tたり\tたる\tだ.\tだ。\tだっ\tった\tって\tてい\tてお\tても\tです\tでに\tでは\tとう\tとし\tとの\tとも\tどう\tない\tなく\tなど\tに,\tに、\tにし\tにな\tには\tの,\tの、\tの子\tは,\tは、\tべき\tまし\tます\tまっ\tまで\tまれ\tらし\tられ\tるる\tれ,\tれ、\tれた\tれて\tれば\tれる\tわれ\tんだ\tんで\tカ月\t会議\t入り\t大会\t始め\t市\t新聞\t日,\t日、\t社会\tｶ月", &[-31, 10, 55, -17, -17, 76, 30, 43, 29, 27, 80, 22, 16, 69, 21, 41, 41, -11, -59, -10, 93, -38, 26, 26, -69, -16, -13, -71, -30, 24, 20, 106, 22, -39, -10, 14, 22, 22, -53, 51, 21, 37, 17, -19, -19, 93, 49, 49, 12, 6, 127, 127, -9, 12, -17, -12, 59, 59, 14, -68, 4, 89, 12, 4, 21, -21, 33, -20, 32, 8, -51, 67, 26, -13, 31, -15, -15, 25, 27, 38, -10, -10, -14, 19, 19, 31, 16, 99, -22, 88, -11, 21, 98, 55, 12, 12, 26, 20, -46, 16, -9, 9, 11, 14, 12, 18, 32, 24, 14, -72, 14, 14, 29, 14]),
+    ("TC1", 14.425197, "AAA\tHHH\tHHM\tHII\tHOH\tHOM\tIHI\tIOH\tIOI\tIOM\tMMH\tOOI", &[76, 71, 40, 69, -27, -23, 81, -10, -70, 32, 13, -127]),
+    ("TC2", 20.858267, "HHO\tHII\tHMM\tIHI\tKKH\tOII", &[100, -49, -55, -94, 34, -127]),
+    ("TC3", 26.716536, "AAA\tHHH\tHHI\tHII\tHIK\tHOH\tIHH\tIHI\tIHO\tIIH\tIIM\tIOI\tKHH\tKKA\tKKH\tKOK\tMHH\tMHM\tMHO\tMMH\tNNH\tNNO\tOHO", &[-11, 13, -13, -41, 27, -56, 5, -114, -72, -31, -39, -20, -46, 18, -46, -38, -101, -17, 5, -18, -63, 25, -127]),
+    ("TC4", 38.149605, "HHH\tHHI\tHHK\tHHM\tHHN\tHHO\tHIH\tHII\tHOH\tIHH\tIHO\tIIH\tIII\tIIO\tIOO\tKAK\tKKA\tKKK\tMHH\tMHI\tMMH\tMMM\tMOM", &[-5, 35, 10, -3, 5, 18, 21, 18, 12, 18, -61, 8, 39, 17, 1, 127, 89, 80, -11, 5, -6, 17, 22]),
+    ("TQ1", 12.559055, "BHHH\tBHHI\tBHIH\tBIHH\tBIII\tBNHH\tBOHH\tBOOO\tOAKK\tOHHH\tOHIH\tOIHI\tOIIH", &[-18, 25, -11, 5, 127, -59, 18, -72, 38, 22, 20, 16, -5]),
+    ("TQ2", 44.02362, "BIHH\tBIII\tBKAK\tBOOO", &[-32, -23, -12, -127]),
+    ("TQ3", 21.984251, "BHHH\tBHHM\tBHIH\tBHII\tBIIH\tBIII\tBMHI\tBMHM\tBOMH\tOHHH\tOHHI\tOHII\tOHMH\tOIHH\tOIIH\tOKAK\tOKHH\tOKKA\tOOHH\tOOII", &[22, -49, 10, -23, -5, -5, -39, -21, 28, 16, 79, 45, 22, 28, 61, 127, 27, 31, 5, -31]),
+    ("TQ4", 64.220474, "BHHH\tBHHM\tBHII\tBIIH\tBIII\tOAAA\tOAKK\tOHHH\tOHHI\tOHHO\tOHIH\tOIHH\tOIHI\tOIIH\tOIII\tOKAK", &[-11, -56, -15, -9, -34, -43, 3, -5, 38, 7, -24, 30, -8, 10, -62, -127]),
+    ("TW1", 36.85827, "につい\t東京都", &[-127, 55]),
+    ("TW2", 63.755905, "ある程\tいった\tころが\tしょう\tその後\tだって\tていた\tとして\tともに\tもので\t一気に\t初めて\t同時に\t大きな\t対して\t社会党", &[-32, -20, -38, 61, -69, -16, 29, -73, -71, 30, -12, -24, -127, -20, -43, -50]),
+    ("TW3", 49.188976, "いただ\tしてい\tとして\tについ\tにとっ\tに当た\tので,\tので、\tのもの\tれから\t十二月", &[-35, 27, -88, -111, -122, -127, -15, -15, -12, -76, -46]),
+    ("TW4", 67.52756, "いう.\tいう。\tからな\tしてい\tたが,\tたが、\tている\tという\tました\tません\tようと\tよると", &[127, 127, -35, 44, 22, 22, 23, 20, 82, 16, -63, 87]),
+    ("UC1", 5.07874, "A\tK\tM\tO", &[95, 18, 127, -99]),
+    ("UC2", 45.472443, "A\tH\tI\tM\tN\tO", &[18, 23, 9, 88, 127, 14]),
+    ("UC3", 18.19685, "A\tI", &[-75, 127]),
+    ("UC4", 52.330708, "A\tH\tI\tK\tM\tN\tO", &[-51, 35, -20, -66, 68, 74, 127]),
+    ("UC5", 9.748032, "H\tI\tK\tM\tO", &[32, -127, -82, 55, -85]),
+    ("UC6", 3.984252, "H\tI\tK\tM\tO", &[-127, -64, 22, 62, -97]),
+    ("UP1", 1.6850394, "O", &[-127]),
+    ("UP2", 7.3622046, "B\tO", &[9, 127]),
+    ("UP3", 1.488189, "B", &[127]),
+    ("UQ1", 19.070866, "BH\tBI\tBK\tBN\tBO\tOH\tOI\tOK\tOO", &[1, -1, -5, 7, -3, -5, 25, 21, -127]),
+    ("UQ2", 13.850393, "BH\tBI\tOK", &[16, 8, 127]),
+    ("UQ3", 116.22835, "BA\tBI\tBK\tBM\tBN\tBO\tOI\tON", &[-4, 16, -62, 27, 55, 127, -7, -28]),
+    ("UW1", 7.4094486, ",\t、\t「\tあ\tう\tが\tき\tこ\tで\tと\tど\tに\tの\tは\tも\tや\tよ\tら\tり\tれ\tを\tん\t・\t主\t京\t区\t午\t国\t大\t委\t市\t日\t理\t生\t県\t都\t｢\t･", &[21, 21, -62, -127, -17, -75, 16, 68, -27, -74, -17, -106, -25, -114, -63, -63, 25, -39, 28, 23, -60, -18, -18, -54, -36, -123, 118, -62, 76, 98, -55, -19, 49, -55, -52, -97, -62, -18]),
+    ("UW2", 30.503937, ",\t、\t〇\t「\t」\tあ\tい\tう\tお\tか\tが\tく\tこ\tさ\tざ\tし\tす\tせ\tそ\tた\tだ\tつ\tて\tで\tと\tど\tな\tに\tの\tは\tひ\tべ\tま\tも\tや\tよ\tり\tる\tれ\tを\tん\tア\tカ\tキ\tッ\t三\t不\t世\t中\t主\t事\t人\t会\t保\t入\t初\t副\t北\t区\t大\t天\t太\t子\t学\t実\t小\t市\t年\t強\t手\t揺\t政\t文\t新\t日\t明\t最\t朝\t本\t東\t果\t次\t民\t気\t理\t発\t目\t相\t県\t立\t第\t米\t自\t行\t西\t見\t調\t議\t込\t開\t間\t｢\t｣\tｯ\tｱ\tｶ\tｷ", &[-27, -27, 29, -21, 103, -18, 17, 4, -16, 48, -28, -14, 37, 29, 18, 50, -22, 10, -33, 6, 60, -31, -10, -9, -32, 42, 35, -58, 4, -13, -42, 41, 20, -41, -13, 54, -19, -23, 19, -82, 69, -19, 10, 19, 27, -25, -70, -10, -32, -28, 16, -4, 32, 12, 18, -99, -51, -112, -14, -58, -28, -16, -50, 25, 34, -66, -27, -35, 35, -50, -34, 50, -44, -55, -60, -48, -21, -60, -54, -31, -22, -78, -6, -57, 25, 17, -52, -8, -38, -25, 27, 17, -44, 27, -24, -127, 33, 39, 100, 58, -41, -21, 103, 27, -19, 10, 19]),
+    ("UW3", 61.157482, ",\t1\t−\t、\t々\t〇\t」\t〓\tあ\tい\tう\tえ\tお\tか\tが\tく\tけ\tげ\tこ\tご\tさ\tし\tす\tせ\tそ\tた\tち\tっ\tつ\tて\tで\tと\tど\tな\tに\tの\tは\tひ\tふ\tへ\tほ\tま\tみ\tめ\tも\tや\tよ\tら\tり\tる\tれ\tわ\tを\tん\tア\tグ\tス\tッ\tト\tム\tル\tロ\tン\t・\t一\t下\t世\t両\t中\t主\t予\t二\t人\t今\t他\t以\t低\t何\t作\t保\t元\t党\t全\t公\t六\t共\t円\t再\t分\t初\t別\t前\t副\t力\t動\t務\t化\t北\t区\t千\t午\t協\t口\t右\t各\t合\t同\t和\t員\t国\t型\t場\t外\t妻\t学\t安\t実\t家\t小\t少\t州\t市\t平\t年\t広\t府\t度\t建\t当\t得\t思\t性\t戸\t指\t政\t教\t数\t文\t新\t日\t旧\t昨\t時\t曜\t最\t月\t期\t李\t村\t東\t核\t森\t業\t氏\t民\t決\t法\t海\t無\t物\t特\t生\t用\t町\t的\t直\t省\t県\t知\t私\t税\t立\t第\t米\t系\t約\t級\t統\t総\t線\t者\t能\t自\t英\t見\t調\t財\t費\t車\t軍\t込\t通\t選\t郎\t郡\t部\t金\t長\t開\t間\t関\t雨\t電\t非\t駅\t１\t｣\t･\tｯ\tｱ\tｽ\tﾄ\tﾑ\tﾙ\tﾛ\tﾝ", &[80, -13, -28, 80, -38, 95, 44, -58, -44, 16, 38, 32, -80, -19, 53, 16, 6, 7, -58, -51, -17, -6, 10, 60, -85, 14, -9, -24, -18, 101, 38, 28, -15, -46, 45, 66, 74, -35, -29, 20, -90, -72, -2, 20, 38, -13, -3, 12, 11, 97, 45, -20, 108, -8, 9, 22, 14, -22, 9, 18, 26, 36, 5, -62, -26, -29, -34, 62, 11, -12, -20, 16, 45, 13, 31, -22, 13, 70, -6, -40, 79, 59, 26, -50, 12, -31, 95, 51, 7, 40, 18, 37, 73, 6, -16, -31, 22, -17, 76, -38, -13, -16, 8, 20, 59, -4, 64, -14, 74, 10, 23, 20, -4, 33, -22, -7, -16, 18, -8, -51, 19, 52, -29, 40, -17, 26, 24, -38, -64, 31, -21, 30, -8, -65, -33, -24, 53, -24, 29, 34, 95, -11, -20, -16, -15, 67, 6, 51, 6, -13, 84, 40, 8, 43, -28, -18, 31, -8, 16, 8, -63, -4, 15, 20, 120, -30, 13, 103, -25, 69, 7, -16, 20, 127, 50, 60, 23, -69, 19, 21, 106, 12, -47, 13, 17, -9, -12, 29, 30, 22, -25, -19, -11, 17, 72, 20, 35, 7, -23, 21, -21, 33, -17, 34, 26, -13, 44, -62, -22, 9, 14, 9, 18, 26, 36, 5]),
+    ("UW4", 117.291336, ",\t.\t―\t、\t。\t〇\t「\t」\t〓\tあ\tい\tう\tえ\tお\tか\tが\tき\tぎ\tく\tけ\tげ\tこ\tご\tさ\tし\tじ\tす\tず\tせ\tそ\tた\tだ\tち\tっ\tつ\tて\tで\tと\tな\tに\tぬ\tね\tの\tは\tば\tひ\tび\tふ\tへ\tべ\tほ\tま\tみ\tむ\tめ\tも\tゃ\tや\tょ\tよ\tら\tり\tる\tれ\tろ\tわ\tを\tん\tカ\tコ\tセ\tッ\tト\tメ\tラ\tリ\tル\tン\t・\tー\t一\t中\t予\t事\t井\t人\t以\t会\t体\t作\t側\t先\t党\t共\t内\t円\t初\t前\t副\t力\t動\t務\t化\t区\t協\t参\t合\t和\t員\t器\t回\t国\t園\t地\t場\t塁\t士\t多\t大\t子\t学\t定\t寺\t小\t屋\t山\t島\t川\t市\t年\t庁\t後\t性\t感\t所\t支\t改\t政\t教\t文\t方\t日\t時\t最\t月\t木\t来\t校\t業\t氏\t民\t気\t沢\t済\t物\t率\t球\t生\t産\t田\t町\t的\t目\t省\t県\t空\t立\t第\t米\t系\t約\t経\t統\t総\t線\t署\t者\t能\t般\t行\t規\t警\t議\t谷\t賞\t車\t軍\t輪\t込\t近\t道\t選\t郎\t都\t野\t銀\t長\t間\t院\t際\t電\t領\t題\t館\t首\t高\t｢\t｣\t･\tｯ\tｰ\tｶ\tｺ\tｾ\tﾄ\tﾒ\tﾗ\tﾘ\tﾙ\tﾝ", &[34, 30, -41, 34, 30, 43, 16, 32, -44, 41, -29, -5, -21, 21, 5, 51, -38, -33, -32, -37, -40, 19, 17, 24, -7, -21, -6, 11, 2, 35, 43, 46, -31, -50, -14, 34, 63, 39, 46, 55, 16, 12, 63, 73, 17, 36, -35, 11, 57, -6, 12, 9, -18, -8, -43, 36, -23, 24, -13, 29, -25, -83, -127, -22, -39, -15, 112, -20, 18, 15, 11, -6, -3, -14, -8, -5, -7, -31, -37, -101, -18, 19, 7, -2, -15, 9, 5, 8, -11, 5, 37, 5, -17, -10, 5, 7, 11, 14, 33, -3, -6, -23, 7, 39, 9, 13, -16, -6, -8, -7, 13, -5, -10, 7, -12, -18, -12, 9, 5, -41, -12, -9, -7, 16, -11, -13, -18, -23, 24, 3, -39, 4, 5, 8, -13, 7, 7, 19, 6, 4, -7, 15, 16, 7, -77, -4, -4, -3, -9, 46, -23, -8, -8, -5, -6, 6, -11, -11, -9, -25, 16, 22, 8, -30, 26, -7, -18, 7, 25, 7, 19, 10, -10, 8, -8, 6, 18, -6, -7, -7, 7, -10, -2, -9, 6, -13, 10, -12, -29, 8, -11, 22, -41, 10, -9, -19, 3, -20, -20, -22, -7, -14, -7, -17, 15, 18, 16, 32, -37, -6, -101, 18, 15, 11, -3, -14, -8, -5, -7, -31]),
+    ("UW5", 258.01575, ",\t.\t1\tE2\t]\t、\t。\t「\tあ\tい\tう\tえ\tお\tか\tが\tき\tぎ\tく\tげ\tさ\tし\tす\tだ\tち\tつ\tで\tど\tな\tに\tの\tは\tべ\tみ\tめ\tゃ\tょ\tり\tる\tれ\tわ\tを\tん\tイ\tル\tン\t中\t京\t会\t党\t務\t区\t告\t員\t大\t学\t定\t嵐\t市\t席\t年\t思\t所\t挙\t新\t日\t月\t査\t格\t機\t氏\t田\t町\t的\t相\t省\t県\t研\t社\t空\t統\t者\t表\t語\t議\t選\t郎\t長\t間\t題\t館\t１\tＥ２\t｢\tｲ\tﾙ\tﾝ", &[2, -1, -2, -127, -11, 2, -1, 1, 6, 1, -2, 5, 2, 3, -2, 6, 8, 1, -4, -6, -5, -3, -5, 4, 4, -3, 7, -3, -5, -2, -2, 4, 2, 3, 13, 3, -1, 2, 2, 2, -5, 1, 1, 2, -1, -3, 3, -4, -3, 14, -3, 3, 8, -5, -2, 7, -5, -12, 4, 7, 3, -3, 6, -7, 1, -17, 4, 5, -6, -5, 1, -15, -12, 5, -4, -16, -4, -1, -3, 8, -9, 3, -4, 5, -4, -1, 3, 5, 9, -3, -2, -127, 1, 1, 2, -1]),
+    ("UW6", 14.259843, ",\t.\t1\tE1\t、\t。\tあ\tう\tか\tが\tく\tこ\tじ\tす\tた\tっ\tて\tで\tと\tな\tに\tの\tは\tも\tり\tる\tを\tル\tン\t一\t中\t件\t会\t前\t区\t員\t委\t学\t市\t広\t後\t業\t相\t社\t福\t空\t者\t連\t郎\t１\tＥ１\tﾙ\tﾝ", &[16, 57, -19, 21, 16, 57, -22, 13, 17, -5, -8, -14, 125, 27, -30, 40, -71, 7, -7, -18, -10, -29, -17, -14, 13, -9, 14, -47, -35, -19, 14, -56, 44, 21, 126, -85, 56, -67, 62, -49, 38, -49, 53, -36, 68, -58, 127, 32, 76, -19, 21, -47, -35]),
+];
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use tantivy::tokenizer::{Token, TokenStream, Tokenizer};
+
+use crate::{Model, Segmenter, Weights};
+
+/// Splits text into the segmenter's words. Whitespace separates words and is
+/// never part of one; offsets are byte offsets into the text and positions
+/// count words.
+pub struct SegmenterTokenizer<W = Model> {
+    segmenter: Arc<Segmenter<W>>,
+}
+
+impl<W> Clone for SegmenterTokenizer<W> {
+    fn clone(&self) -> Self {
+        SegmenterTokenizer { segmenter: Arc::clone(&self.segmenter) }
+    }
+}
+
+impl<W: Weights> SegmenterTokenizer<W> {
+    pub fn new(segmenter: Segmenter<W>) -> SegmenterTokenizer<W> {
+        SegmenterTokenizer { segmenter: Arc::new(segmenter) }
+    }
+
+    /// Shares a segmenter with other tokenizers or with the rest of the
+    /// application.
+    pub fn from_shared(segmenter: Arc<Segmenter<W>>) -> SegmenterTokenizer<W> {
+        SegmenterTokenizer { segmenter }
+    }
+}
+
+impl<W: Weights + Send + Sync + 'static> Tokenizer for SegmenterTokenizer<W> {
+    type TokenStream<'a> = SegmenterTokenStream;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> SegmenterTokenStream {
+        let tokens = self.segmenter.segment_spans(text).into_iter()
+            .enumerate()
+            .map(|(position, (text, span))| Token { offset_from: span.start, offset_to: span.end, position, text, position_length: 1 })
+            .collect();
+        SegmenterTokenStream { tokens, index: 0 }
+    }
+}
+
+/// The words of one text, segmented up front.
+pub struct SegmenterTokenStream {
+    tokens: Vec<Token>,
+    index: usize,
+}
+
+impl TokenStream for SegmenterTokenStream {
+    fn advance(&mut self) -> bool {
+        if self.index < self.tokens.len() {
+            self.index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.tokens[self.index - 1]
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.tokens[self.index - 1]
+    }
+}
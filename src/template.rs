@@ -0,0 +1,139 @@
+use std::fmt;
+
+/// What a template reads at one position around a gap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    /// The character itself (`W`).
+    Char,
+    /// Its character type (`C`), one of `H I K A N M O`.
+    Type,
+    /// The decision taken at an earlier gap (`P`): `B`, `O` or `U`.
+    Decision,
+    /// Dictionary words around the gap (`D`), see `Dictionary`. Takes no
+    /// offset and must be the template's only slot.
+    Dictionary,
+}
+
+/// One position of a template. Character offsets count from the gap: `-1` is
+/// the character before it and `1` the character after it. Decision offsets
+/// count earlier gaps: `-1` is the previous gap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slot {
+    pub source: Source,
+    pub offset: i32,
+}
+
+impl Slot {
+    fn parse(token: &str) -> Result<Slot, String> {
+        if token == "D" {
+            return Ok(Slot { source: Source::Dictionary, offset: 0 });
+        }
+        let mut chars = token.chars();
+        let source = match chars.next() {
+            Some('W') => Source::Char,
+            Some('C') => Source::Type,
+            Some('P') => Source::Decision,
+            _ => return Err(format!("slot `{}` must start with W, C or P", token)),
+        };
+        let offset: i32 = chars.as_str().parse().map_err(|_| format!("slot `{}` has no valid offset", token))?;
+        if offset == 0 {
+            return Err(format!("slot `{}`: offset 0 is not a position", token));
+        }
+        if source == Source::Decision && offset > 0 {
+            return Err(format!("slot `{}`: decisions are only known for earlier gaps", token));
+        }
+        Ok(Slot { source, offset })
+    }
+}
+
+impl fmt::Display for Slot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let letter = match self.source {
+            Source::Char => 'W',
+            Source::Type => 'C',
+            Source::Decision => 'P',
+            Source::Dictionary => return write!(f, "D"),
+        };
+        write!(f, "{}{}", letter, self.offset)
+    }
+}
+
+/// A named feature template: the feature is the concatenation of what each
+/// slot reads, in order. A dictionary template instead yields one feature per
+/// dictionary match at the gap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    pub name: String,
+    pub slots: Vec<Slot>,
+}
+
+impl Template {
+    /// Parses a space-separated slot list such as `P-1 C-1 C1`.
+    pub fn parse(name: &str, spec: &str) -> Result<Template, String> {
+        // The keywords would be ambiguous with weight lines in a model file.
+        let reserved = ["BIAS", "TEMPLATE", "QUANT", "SCALE"].contains(&name);
+        if reserved || name.is_empty() || name.contains(|c: char| c.is_whitespace() || c == ':') {
+            return Err(format!("invalid template name `{}`", name));
+        }
+        let slots = spec.split_whitespace().map(Slot::parse).collect::<Result<Vec<Slot>, String>>()?;
+        if slots.is_empty() {
+            return Err(format!("template `{}` has no slots", name));
+        }
+        if slots.len() > 1 && slots.iter().any(|s| s.source == Source::Dictionary) {
+            return Err(format!("template `{}`: D cannot be combined with other slots", name));
+        }
+        Ok(Template { name: name.to_string(), slots })
+    }
+
+    /// A template over dictionary matches, named `name`.
+    pub fn dictionary(name: &str) -> Template {
+        Template { name: name.to_string(), slots: vec![Slot { source: Source::Dictionary, offset: 0 }] }
+    }
+
+    pub fn is_dictionary(&self) -> bool {
+        self.slots.iter().any(|s| s.source == Source::Dictionary)
+    }
+
+    /// The slot list in the form `parse` accepts.
+    pub fn spec(&self) -> String {
+        self.slots.iter().map(|s| s.to_string()).collect::<Vec<String>>().join(" ")
+    }
+}
+
+/// The templates of the reference TinySegmenter, in scoring order.
+pub const DEFAULT_TEMPLATES: [(&str, &str); 42] = [
+    ("UP1", "P-3"), ("UP2", "P-2"), ("UP3", "P-1"),
+    ("BP1", "P-3 P-2"), ("BP2", "P-2 P-1"),
+    ("UW1", "W-3"), ("UW2", "W-2"), ("UW3", "W-1"), ("UW4", "W1"), ("UW5", "W2"), ("UW6", "W3"),
+    ("BW1", "W-2 W-1"), ("BW2", "W-1 W1"), ("BW3", "W1 W2"),
+    ("TW1", "W-3 W-2 W-1"), ("TW2", "W-2 W-1 W1"), ("TW3", "W-1 W1 W2"), ("TW4", "W1 W2 W3"),
+    ("UC1", "C-3"), ("UC2", "C-2"), ("UC3", "C-1"), ("UC4", "C1"), ("UC5", "C2"), ("UC6", "C3"),
+    ("BC1", "C-2 C-1"), ("BC2", "C-1 C1"), ("BC3", "C1 C2"),
+    ("TC1", "C-3 C-2 C-1"), ("TC2", "C-2 C-1 C1"), ("TC3", "C-1 C1 C2"), ("TC4", "C1 C2 C3"),
+    ("UQ1", "P-3 C-3"), ("UQ2", "P-2 C-2"), ("UQ3", "P-1 C-1"),
+    ("BQ1", "P-2 C-2 C-1"), ("BQ2", "P-2 C-1 C1"), ("BQ3", "P-1 C-2 C-1"), ("BQ4", "P-1 C-1 C1"),
+    ("TQ1", "P-2 C-3 C-2 C-1"), ("TQ2", "P-2 C-2 C-1 C1"), ("TQ3", "P-1 C-3 C-2 C-1"), ("TQ4", "P-1 C-2 C-1 C1"),
+];
+
+/// Parses `(name, spec)` pairs such as `DEFAULT_TEMPLATES`.
+pub fn parse_all(specs: &[(&str, &str)]) -> Result<Vec<Template>, String> {
+    specs.iter().map(|(name, spec)| Template::parse(name, spec)).collect()
+}
+
+pub fn default_templates() -> Vec<Template> {
+    parse_all(&DEFAULT_TEMPLATES).expect("default templates are valid")
+}
+
+pub fn is_default(templates: &[Template]) -> bool {
+    templates.len() == DEFAULT_TEMPLATES.len()
+        && templates.iter().zip(DEFAULT_TEMPLATES).all(|(t, (name, spec))| t.name == name && t.spec() == spec)
+}
+
+/// `TEMPLATE<TAB>name<TAB>spec` lines for a model file; empty for the
+/// default templates, which files leave implicit.
+pub fn to_native(templates: &[Template]) -> String {
+    if is_default(templates) {
+        return String::new();
+    }
+    templates.iter().map(|t| format!("TEMPLATE\t{}\t{}\n", t.name, t.spec())).collect()
+}
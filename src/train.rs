@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+
+use crate::compress::prune;
+use crate::corpus::{Annotation, Gap};
+use crate::eval::evaluate_annotated;
+use crate::template::Template;
+use crate::{construct_hashmap, extract_features, walk_gaps, Decision, Dictionary, Model, Segmenter, Sentence};
+
+/// Online update rule.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Algorithm {
+    /// Averaged perceptron: on every misclassified gap, move each active
+    /// feature by `rate` towards the gold decision.
+    AveragedPerceptron { rate: f64 },
+    /// PA-I: whenever the score is on the wrong side of `margin`, take the
+    /// smallest step that fixes it, capped at `c` per feature.
+    PassiveAggressive { c: f64, margin: f64 },
+}
+
+impl Default for Algorithm {
+    fn default() -> Self {
+        Algorithm::AveragedPerceptron { rate: 100.0 }
+    }
+}
+
+/// A weight and the step-weighted sum of its updates, for averaging.
+#[derive(Debug, Default, Clone, Copy)]
+struct Param {
+    w: f64,
+    u: f64,
+}
+
+impl Param {
+    fn update(&mut self, delta: f64, step: f64) {
+        self.w += delta;
+        self.u += step * delta;
+    }
+
+    fn averaged(&self, steps: f64) -> f64 {
+        self.w - self.u / steps
+    }
+}
+
+/// Updates a model's weights one corrected sentence at a time, over the same
+/// feature templates the scorer uses. History features see the gold decisions
+/// where they are known and the current weights' predictions elsewhere.
+pub struct OnlineLearner {
+    char_map: HashMap<char, char>,
+    dictionary: Option<Dictionary>,
+    templates: Vec<Template>,
+    algorithm: Algorithm,
+    bias: Param,
+    tables: HashMap<String, HashMap<String, Param>>,
+    steps: f64,
+}
+
+impl OnlineLearner {
+    /// Starts from the weights of `model`.
+    pub fn new(model: &Model, algorithm: Algorithm) -> OnlineLearner {
+        let tables = model.tables.iter()
+            .map(|(name, table)| {
+                let params = table.iter().map(|(k, w)| (k.clone(), Param { w: *w as f64, u: 0.0 })).collect();
+                (name.clone(), params)
+            })
+            .collect();
+        OnlineLearner {
+            char_map: construct_hashmap(),
+            dictionary: None,
+            templates: model.templates.clone(),
+            algorithm,
+            bias: Param { w: model.bias as f64, u: 0.0 },
+            tables,
+            steps: 1.0,
+        }
+    }
+
+    /// Adds dictionary features over `dictionary`, with a `DICT` template
+    /// unless the model already has a dictionary template.
+    pub fn with_dictionary(mut self, dictionary: Dictionary) -> OnlineLearner {
+        if !self.templates.iter().any(|t| t.is_dictionary()) {
+            self.templates.push(Template::dictionary("DICT"));
+        }
+        self.dictionary = Some(dictionary);
+        self
+    }
+
+    fn score(&self, features: &[(&str, String)]) -> f64 {
+        let mut score = self.bias.w;
+        for (template, feature) in features {
+            if let Some(p) = self.tables.get(*template).and_then(|t| t.get(feature)) {
+                score += p.w;
+            }
+        }
+        score
+    }
+
+    /// The weight change for one gap, or `None` if no update is needed.
+    fn delta(&self, y: f64, score: f64, active: usize) -> Option<f64> {
+        match self.algorithm {
+            Algorithm::AveragedPerceptron { rate } => {
+                if y * score <= 0.0 { Some(y * rate) } else { None }
+            }
+            Algorithm::PassiveAggressive { c, margin } => {
+                let loss = margin - y * score;
+                if loss > 0.0 { Some(y * c.min(loss / active as f64)) } else { None }
+            }
+        }
+    }
+
+    /// Decides every gap of `text` by `gold(position)` (the number of
+    /// characters before the gap) and updates the weights where the current
+    /// model disagrees. Gaps without a gold decision are decided by the current
+    /// weights and left out of training. Returns the number of gaps updated.
+    fn learn_gaps<G>(&mut self, text: &str, mut gold: G) -> usize
+    where
+        G: FnMut(usize) -> Option<Decision>,
+    {
+        if text.is_empty() { return 0; }
+
+        let sentence = Sentence::new(text, &self.char_map, self.dictionary.as_ref());
+        let mut updates = 0;
+        walk_gaps(&sentence, |window| {
+            let features = extract_features(&self.templates, window);
+            let score = self.score(&features);
+            let decision = match gold(window.position) {
+                Some(decision) => decision,
+                None => return if score > 0.0 { Decision::Boundary } else { Decision::Inside },
+            };
+            let y = if decision == Decision::Boundary { 1.0 } else { -1.0 };
+            // The bias is always active, hence the `+ 1`.
+            if let Some(delta) = self.delta(y, score, features.len() + 1) {
+                let step = self.steps;
+                self.bias.update(delta, step);
+                for (template, feature) in features {
+                    self.tables.entry(template.to_string()).or_default().entry(feature).or_default().update(delta, step);
+                }
+                updates += 1;
+            }
+            self.steps += 1.0;
+            decision
+        });
+        updates
+    }
+
+    /// Learns from the labelled gaps of one sentence.
+    pub fn learn(&mut self, sentence: &Annotation) -> usize {
+        self.learn_gaps(&sentence.text, |position| match sentence.gap(position) {
+            Gap::Boundary => Some(Decision::Boundary),
+            Gap::Inside => Some(Decision::Inside),
+            Gap::Unknown => None,
+        })
+    }
+
+    /// One pass over `corpus`; returns the number of gaps updated.
+    pub fn epoch(&mut self, corpus: &[Annotation]) -> usize {
+        corpus.iter().map(|sentence| self.learn(sentence)).sum()
+    }
+
+    /// The learned weights, rounded to integers. The averaged perceptron
+    /// returns the average over all steps so far.
+    pub fn model(&self) -> Model {
+        let value = |p: &Param| match self.algorithm {
+            Algorithm::AveragedPerceptron { .. } => p.averaged(self.steps),
+            Algorithm::PassiveAggressive { .. } => p.w,
+        };
+        let tables = self.tables.iter()
+            .map(|(name, params)| {
+                let table = params.iter()
+                    .map(|(k, p)| (k.clone(), value(p).round() as i32))
+                    .filter(|(_, w)| *w != 0)
+                    .collect();
+                (name.clone(), table)
+            })
+            .collect();
+        Model { templates: self.templates.clone(), bias: value(&self.bias).round() as i32, tables }
+    }
+}
+
+/// Runs `epochs` passes of online learning over `corpus`, starting from `model`.
+pub fn update_model(model: &Model, corpus: &[Annotation], algorithm: Algorithm, epochs: usize) -> Model {
+    let mut learner = OnlineLearner::new(model, algorithm);
+    for _ in 0..epochs {
+        learner.epoch(corpus);
+    }
+    learner.model()
+}
+
+/// Boundary F1 of every fold of a cross-validation run after every epoch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrossValidation {
+    /// `f1[fold][epoch - 1]`.
+    pub f1: Vec<Vec<f64>>,
+}
+
+impl CrossValidation {
+    /// Mean and sample standard deviation of F1 across folds after `epoch`.
+    pub fn summary(&self, epoch: usize) -> (f64, f64) {
+        let values: Vec<f64> = self.f1.iter().map(|fold| fold[epoch - 1]).collect();
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        let variance = if values.len() < 2 { 0.0 } else { values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0) };
+        (mean, variance.sqrt())
+    }
+
+    pub fn epochs(&self) -> usize {
+        self.f1.first().map_or(0, |fold| fold.len())
+    }
+}
+
+/// Splits `corpus` into `folds` interleaved parts and, for each, trains on the
+/// others starting from `model`, with dictionary features if `dictionary` is
+/// given. After every epoch the model, pruned of weights below `min_weight`,
+/// is scored on the held-out part.
+pub fn cross_validate(
+    model: &Model,
+    dictionary: Option<&Dictionary>,
+    corpus: &[Annotation],
+    algorithm: Algorithm,
+    folds: usize,
+    epochs: usize,
+    min_weight: i32,
+) -> CrossValidation {
+    let f1 = (0..folds)
+        .map(|fold| {
+            let (held_out, training): (Vec<_>, Vec<_>) = corpus.iter().enumerate().partition(|(i, _)| i % folds == fold);
+            let held_out: Vec<Annotation> = held_out.into_iter().map(|(_, s)| s.clone()).collect();
+            let training: Vec<Annotation> = training.into_iter().map(|(_, s)| s.clone()).collect();
+            let mut learner = OnlineLearner::new(model, algorithm);
+            if let Some(dictionary) = dictionary {
+                learner = learner.with_dictionary(dictionary.clone());
+            }
+            (0..epochs)
+                .map(|_| {
+                    learner.epoch(&training);
+                    let mut segmenter = Segmenter::with_model(prune(&learner.model(), min_weight, None));
+                    if let Some(dictionary) = dictionary {
+                        segmenter = segmenter.with_dictionary(dictionary.clone());
+                    }
+                    evaluate_annotated(&segmenter, &held_out).f1()
+                })
+                .collect()
+        })
+        .collect();
+    CrossValidation { f1 }
+}
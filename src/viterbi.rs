@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use crate::template::{Source, Template};
+use crate::{extract_features, get_score, Decision, Sentence, SegmenterConfig, Weights, Window};
+
+/// One reachable history state after a gap.
+struct Node {
+    /// The last `depth` decisions, oldest first.
+    state: Vec<Decision>,
+    score: i64,
+    /// Index of the node before it in the previous layer.
+    back: usize,
+    decision: Decision,
+}
+
+fn reads_history(template: &Template) -> bool {
+    template.slots.iter().any(|s| s.source == Source::Decision)
+}
+
+/// How many earlier decisions the history features of `model` can see.
+fn history_depth<W: Weights>(model: &W) -> usize {
+    model.templates().iter()
+        .flat_map(|t| &t.slots)
+        .filter(|s| s.source == Source::Decision)
+        .map(|s| s.offset.unsigned_abs() as usize)
+        .max()
+        .unwrap_or(0)
+}
+
+fn partial_score<W: Weights>(templates: &[Template], window: &Window, model: &W) -> i64 {
+    extract_features(templates, window).iter().map(|(template, feature)| get_score(template, feature, model) as i64).sum()
+}
+
+/// The decisions at every gap of `sentence` maximising the sum of each
+/// gap's margin over the threshold, counted positively at boundaries and
+/// negatively elsewhere. Only the last few decisions reach the history
+/// features, so dynamic programming over those states finds the exact
+/// optimum: 2^3 states for the default templates.
+pub(crate) fn best_path<W: Weights>(sentence: &Sentence, model: &W, config: &SegmenterConfig) -> Vec<Decision> {
+    let depth = history_depth(model);
+    let offset = config.bias(model) as i64 - config.threshold as i64;
+    // Features that do not read the history are the same in every state.
+    let (dynamic, fixed): (Vec<Template>, Vec<Template>) = model.templates().iter().cloned().partition(reads_history);
+    let mut layers: Vec<Vec<Node>> = Vec::new();
+    let mut previous = vec![Node { state: Vec::new(), score: 0, back: 0, decision: Decision::Unknown }];
+
+    for position in 1..sentence.chars.len() {
+        let mut layer: Vec<Node> = Vec::new();
+        let mut index: HashMap<Vec<Decision>, usize> = HashMap::new();
+        let base = offset + partial_score(&fixed, &Window { sentence, position, history: &[] }, model);
+        for (back, node) in previous.iter().enumerate() {
+            let window = Window { sentence, position, history: &node.state };
+            let margin = base + partial_score(&dynamic, &window, model);
+            // Inside first, so that ties are not splits, as in greedy decoding.
+            for (decision, gain) in [(Decision::Inside, -margin), (Decision::Boundary, margin)] {
+                if sentence.constraint(position).is_some_and(|forced| forced != decision) {
+                    continue;
+                }
+                let mut state = node.state.clone();
+                state.push(decision);
+                if state.len() > depth {
+                    state.remove(0);
+                }
+                let candidate = Node { state, score: node.score + gain, back, decision };
+                match index.get(&candidate.state) {
+                    Some(&i) if layer[i].score >= candidate.score => {}
+                    Some(&i) => layer[i] = candidate,
+                    None => {
+                        index.insert(candidate.state.clone(), layer.len());
+                        layer.push(candidate);
+                    }
+                }
+            }
+        }
+        layers.push(std::mem::replace(&mut previous, layer));
+    }
+    layers.push(previous);
+
+    // Follow the back pointers from the best final state.
+    let last = layers.last().expect("at least the start layer");
+    let mut i = (0..last.len()).rev().max_by_key(|&i| last[i].score).unwrap_or(0);
+    let mut path = Vec::with_capacity(layers.len() - 1);
+    for layer in layers.iter().skip(1).rev() {
+        path.push(layer[i].decision);
+        i = layer[i].back;
+    }
+    path.reverse();
+    path
+}
@@ -0,0 +1,18 @@
+use std::ptr;
+
+/// A zeroed buffer of `len` bytes in linear memory, for JavaScript to copy
+/// text or model bytes into before calling the C API.
+#[no_mangle]
+pub extern "C" fn tinysegmenter_alloc(len: usize) -> *mut u8 {
+    Box::into_raw(vec![0u8; len].into_boxed_slice()) as *mut u8
+}
+
+/// Frees a buffer from `tinysegmenter_alloc`.
+///
+/// # Safety
+/// `buffer` must come from `tinysegmenter_alloc(len)` and must not be used
+/// afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn tinysegmenter_dealloc(buffer: *mut u8, len: usize) {
+    drop(Box::from_raw(ptr::slice_from_raw_parts_mut(buffer, len)));
+}
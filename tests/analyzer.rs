@@ -0,0 +1,117 @@
+//! The search analyzer and its filters.
+
+use tinysegmenter::analyzer::{Length, Normalize, Punctuation, StopWords, Token, TokenFilter, TokenKind};
+use tinysegmenter::{Analyzer, Segmenter};
+
+fn token(text: &str) -> Token {
+    Token { text: text.to_string(), offset_from: 0, offset_to: text.len(), position: 0, kind: TokenKind::Word }
+}
+
+#[test]
+fn search_profile_drops_particles_and_punctuation() {
+    let analyzer = Analyzer::search();
+    assert_eq!(analyzer.terms("私の名前は中野です。"), ["私", "名前", "中野"]);
+    assert_eq!(analyzer.terms("「ＴＯＫＹＯ」に行きました！"), ["tokyo", "行き"]);
+}
+
+#[test]
+fn offsets_and_positions_refer_to_the_original_text() {
+    let text = "ＡＢＣの  名前";
+    let tokens = Analyzer::search().analyze(text);
+    assert_eq!(tokens.iter().map(|t| &text[t.offset_from..t.offset_to]).collect::<Vec<_>>(), ["ＡＢＣ", "名前"]);
+    assert_eq!(tokens[0].text, "abc");
+    assert_eq!(tokens.iter().map(|t| t.position).collect::<Vec<_>>(), [0, 2]);
+}
+
+#[test]
+fn filters_compose_in_order() {
+    let analyzer = Analyzer::new(Segmenter::new()).filter(StopWords::new(["名前"])).filter(Length::at_least(2));
+    assert_eq!(analyzer.terms("私の名前は中野です"), ["中野", "です"]);
+    assert_eq!(Analyzer::new(Segmenter::new()).terms("私の名前"), Segmenter::new().segment("私の名前"));
+}
+
+#[test]
+fn normalization_unifies_widths_and_case() {
+    let normalize = Normalize::default();
+    assert_eq!(normalize.normalize("ｺﾝﾋﾟｭｰﾀｰ"), "コンピューター");
+    assert_eq!(normalize.normalize("ｶﾞｯﾂﾞ ｳﾞｧ ﾎﾟﾝ ｱﾞ"), "ガッヅ ヴァ ポン ア゛");
+    assert_eq!(normalize.normalize("ｉＰｈｏｎｅ１５"), "iphone15");
+    assert_eq!(Normalize { width: true, lowercase: false }.normalize("Ｘ"), "X");
+    assert_eq!(Normalize { width: false, lowercase: true }.normalize("ＡB"), "ａb");
+}
+
+#[test]
+fn punctuation_keeps_words_of_any_script() {
+    let punctuation = Punctuation::default();
+    for kept in ["名前", "abc", "한국", "a-b"] {
+        let mut t = token(kept);
+        assert!(punctuation.apply(&mut t), "{}", kept);
+        assert_eq!(t.text, kept);
+    }
+    let mut quoted = token("「名前」");
+    assert!(punctuation.apply(&mut quoted));
+    assert_eq!(quoted.text, "名前");
+    for dropped in ["。", "、", "「」", "!?", "★"] {
+        assert!(!punctuation.apply(&mut token(dropped)), "{}", dropped);
+    }
+}
+
+#[test]
+fn length_bounds_count_characters() {
+    let length = Length { min: 2, max: 3 };
+    assert!(!length.apply(&mut token("名")));
+    assert!(length.apply(&mut token("名前")));
+    assert!(!length.apply(&mut token("コンピューター")));
+}
+
+#[test]
+fn bigrams_cover_kanji_and_katakana_spans() {
+    let text = "東京都のスカイツリー";
+    let tokens = Analyzer::new(Segmenter::new()).with_bigrams().analyze(text);
+    for token in &tokens {
+        assert_eq!(&text[token.offset_from..token.offset_to], token.text);
+    }
+    let bigrams: Vec<&str> = tokens.iter().filter(|t| t.kind == TokenKind::Bigram).map(|t| t.text.as_str()).collect();
+    assert_eq!(bigrams, ["東京", "京都", "スカ", "カイ", "イツ", "ツリ", "リー"]);
+    let words: Vec<String> = tokens.iter().filter(|t| t.kind == TokenKind::Word).map(|t| t.text.clone()).collect();
+    assert_eq!(words, Segmenter::new().segment(text));
+    // Ordered by offset, each bigram at the position of its first character's word.
+    assert!(tokens.windows(2).all(|p| p[0].offset_from <= p[1].offset_from));
+    let word_at = |t: &Token| tokens.iter().find(|w| w.kind == TokenKind::Word && w.offset_from <= t.offset_from && t.offset_from < w.offset_to).unwrap().position;
+    assert!(tokens.iter().all(|t| t.position == word_at(t)));
+}
+
+#[test]
+fn bigram_offsets_slice_half_width_katakana_back() {
+    let text = "ｶﾞｲﾄﾞﾌﾞｯｸ 東京ｽｶｲﾂﾘｰ";
+    let raw = Analyzer::new(Segmenter::new()).with_bigrams().analyze(text);
+    let bigrams: Vec<&Token> = raw.iter().filter(|t| t.kind == TokenKind::Bigram).collect();
+    let texts: Vec<&str> = bigrams.iter().map(|t| t.text.as_str()).collect();
+    assert!(texts.starts_with(&["ｶﾞｲ", "ｲﾄﾞ", "ﾄﾞﾌﾞ", "東京"]), "{:?}", texts);
+    assert!(bigrams.iter().all(|t| !t.text.contains(' ')));
+    for token in &raw {
+        assert_eq!(&text[token.offset_from..token.offset_to], token.text);
+    }
+
+    // Normalizing shortens the texts but not the offsets.
+    let normalize = Normalize::default();
+    let normalized = Analyzer::new(Segmenter::new()).with_bigrams().filter(normalize).analyze(text);
+    assert_eq!(normalized.len(), raw.len());
+    for (token, original) in normalized.iter().zip(&raw) {
+        assert_eq!((token.offset_from, token.offset_to, token.position), (original.offset_from, original.offset_to, original.position));
+        assert_eq!(normalize.normalize(&text[token.offset_from..token.offset_to]), token.text);
+    }
+    let bigrams: Vec<&str> = normalized.iter().filter(|t| t.kind == TokenKind::Bigram).map(|t| t.text.as_str()).collect();
+    assert!(bigrams.starts_with(&["ガイ", "イド", "ドブ", "東京"]), "{:?}", bigrams);
+    assert!(bigrams.iter().all(|t| t.chars().count() == 2), "{:?}", bigrams);
+}
+
+#[test]
+fn filters_apply_to_bigrams() {
+    let analyzer = Analyzer::new(Segmenter::new()).with_bigrams().filter(Normalize::default()).filter(StopWords::new(["ｶｲ", "カイ"]));
+    let terms = analyzer.terms("ｽｶｲ ツリー");
+    assert!(terms.contains(&String::from("スカ")));
+    assert!(!terms.contains(&String::from("カイ")));
+    assert!(terms.contains(&String::from("リー")));
+    assert!(!Analyzer::search().terms("東京都").contains(&String::from("東京")));
+}
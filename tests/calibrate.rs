@@ -0,0 +1,75 @@
+//! Decision threshold, bias override and their calibration.
+
+mod common;
+
+use tinysegmenter::calibrate::{average_token_length, calibrate_f1, calibrate_token_length};
+use tinysegmenter::eval::{boundaries, evaluate};
+use tinysegmenter::{Segmenter, SegmenterConfig};
+
+fn with_config(threshold: i32, bias: Option<i32>) -> Segmenter {
+    Segmenter::new().with_config(SegmenterConfig { threshold, bias, ..Default::default() })
+}
+
+#[test]
+fn default_config_matches_plain_segmenter() {
+    let plain = Segmenter::new();
+    let configured = with_config(0, None);
+    for text in common::texts() {
+        assert_eq!(configured.segment(&text), plain.segment(&text));
+    }
+}
+
+#[test]
+fn threshold_moves_boundaries_where_scores_cross_it() {
+    let plain = Segmenter::new();
+    for text in common::texts() {
+        let scores = plain.gap_scores(&text);
+        let threshold = scores[0];
+        let raised = with_config(threshold, None);
+        // The first gap has no history, so its score is unchanged.
+        assert_eq!(raised.gap_scores(&text)[0], 0);
+        assert!(!boundaries(&raised.segment(&text)).contains(&1));
+    }
+    let coarse = average_token_length(&with_config(5000, None), &common::texts());
+    let fine = average_token_length(&with_config(-5000, None), &common::texts());
+    assert!(coarse > average_token_length(&Segmenter::new(), &common::texts()));
+    assert!(fine < average_token_length(&Segmenter::new(), &common::texts()));
+}
+
+#[test]
+fn bias_override_shifts_every_score() {
+    let plain = Segmenter::new();
+    let shifted = with_config(0, Some(plain.model().bias + 100_000));
+    let text = "私の名前は西村です";
+    assert_eq!(shifted.segment(text).len(), text.chars().count());
+    let explained = shifted.explain(text);
+    assert_eq!(explained[0].bias, plain.model().bias + 100_000);
+    assert_eq!(explained[0].score, plain.explain(text)[0].score + 100_000);
+}
+
+#[test]
+fn token_length_calibration_reaches_the_target() {
+    let segmenter = Segmenter::new();
+    let (config, length) = calibrate_token_length(&segmenter, &common::texts(), 3.0);
+    assert!((length - 3.0).abs() < 0.5, "{}", length);
+    assert_eq!(average_token_length(&segmenter.with_config(config), &common::texts()), length);
+
+    let (_, whole) = calibrate_token_length(&Segmenter::new(), &common::texts(), 100.0);
+    let longest = common::texts().iter().map(|t| t.chars().count()).sum::<usize>() as f64 / common::texts().len() as f64;
+    assert_eq!(whole, longest);
+}
+
+#[test]
+fn f1_calibration_is_no_worse_than_the_default() {
+    let dev: Vec<Vec<String>> = [
+        vec!["コンピューター", "の", "使い方", "が", "よく", "わかり", "ませ", "ん", "。"],
+        vec!["私", "の", "名前", "は", "西村", "です"],
+    ]
+    .iter()
+    .map(|words| words.iter().map(|w| w.to_string()).collect())
+    .collect();
+    let segmenter = Segmenter::new();
+    let (config, scores) = calibrate_f1(&segmenter, &dev);
+    assert!(scores.f1() >= evaluate(&segmenter, &dev).f1());
+    assert_eq!(evaluate(&segmenter.with_config(config), &dev), scores);
+}
@@ -0,0 +1,65 @@
+//! Builds the C test program against the cdylib and the header, and runs it.
+#![cfg(unix)]
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The directory holding the test executable and the cdylib built with it.
+fn deps_dir() -> PathBuf {
+    env::current_exe().unwrap().parent().unwrap().to_path_buf()
+}
+
+#[test]
+fn c_program_segments_through_the_c_api() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let deps = deps_dir();
+    let library = deps.join(format!("{}tinysegmenter{}", env::consts::DLL_PREFIX, env::consts::DLL_SUFFIX));
+    assert!(library.exists(), "{} was not built", library.display());
+
+    let out = env::temp_dir().join(format!("tinysegmenter-capi-{}", std::process::id()));
+    fs::create_dir_all(&out).unwrap();
+    let program = out.join("segment");
+    let status = Command::new(env::var("CC").unwrap_or_else(|_| String::from("cc")))
+        .arg("-std=c99")
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-I")
+        .arg(root.join("include"))
+        .arg(root.join("tests/capi/segment.c"))
+        .arg("-o")
+        .arg(&program)
+        .arg(&library)
+        .arg(format!("-Wl,-rpath,{}", deps.display()))
+        .status()
+        .expect("a C compiler is needed for this test");
+    assert!(status.success());
+
+    let model = out.join("split.model");
+    fs::write(&model, "BIAS\t-1\n").unwrap();
+    let output = Command::new(&program).arg(&model).output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "{}{}", stdout, String::from_utf8_lossy(&output.stderr));
+    assert_eq!(stdout, "私 の 名前 は 中野 です\n東 京 都\n東京都\nok\n");
+    fs::remove_dir_all(&out).unwrap();
+}
+
+#[test]
+fn header_is_generated_from_the_source() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let config = cbindgen::Config::from_file(root.join("cbindgen.toml")).unwrap();
+    let mut generated = Vec::new();
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(root.join("src/ffi.rs"))
+        .generate()
+        .expect("src/ffi.rs is parseable")
+        .write(&mut generated);
+    let path = root.join("include/tinysegmenter.h");
+    if env::var_os("UPDATE_HEADER").is_some() {
+        fs::write(&path, &generated).unwrap();
+    }
+    let header = fs::read(&path).unwrap();
+    assert!(header == generated, "{} is out of date; rerun with UPDATE_HEADER=1", path.display());
+}
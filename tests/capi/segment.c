@@ -0,0 +1,70 @@
+/* Exercises the C API; run by tests/capi.rs. Prints one line per word of
+ * each segmented text, then "ok". Exits non-zero on the first failure. */
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "tinysegmenter.h"
+
+#define CHECK(cond)                                                  \
+  do {                                                               \
+    if (!(cond)) {                                                   \
+      fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #cond); \
+      exit(1);                                                       \
+    }                                                                \
+  } while (0)
+
+static void print_words(const TinySegmenter *segmenter, const char *text) {
+  TsSegments segments;
+  CHECK(tinysegmenter_segment(segmenter, text, strlen(text), &segments) == 0);
+  for (size_t i = 0; i < segments.len; i++) {
+    TsSpan span = segments.spans[i];
+    printf("%.*s%s", (int)(span.end - span.start), text + span.start, i + 1 < segments.len ? " " : "\n");
+  }
+  tinysegmenter_segments_free(&segments);
+  CHECK(segments.spans == NULL && segments.len == 0);
+}
+
+int main(int argc, char **argv) {
+  CHECK(argc == 2);
+  CHECK(tinysegmenter_abi_version() == TINYSEGMENTER_ABI_VERSION);
+
+  TinySegmenter *builtin = tinysegmenter_new();
+  CHECK(builtin != NULL);
+  print_words(builtin, "私の名前は中野です");
+
+  /* An empty text has no words. */
+  TsSegments empty;
+  CHECK(tinysegmenter_segment(builtin, "", 0, &empty) == 0);
+  CHECK(empty.len == 0);
+  tinysegmenter_segments_free(&empty);
+
+  /* Invalid UTF-8 is an error. */
+  TsSegments invalid;
+  CHECK(tinysegmenter_segment(builtin, "\xff", 1, &invalid) == -1);
+  CHECK(invalid.spans == NULL && invalid.len == 0);
+  CHECK(strstr(tinysegmenter_last_error(), "UTF-8") != NULL);
+  tinysegmenter_free(builtin);
+
+  /* A model that splits everywhere, from bytes. */
+  const char *model = "BIAS\t1\n";
+  TinySegmenter *split = tinysegmenter_from_bytes((const uint8_t *)model, strlen(model));
+  CHECK(split != NULL);
+  print_words(split, "東京都");
+  tinysegmenter_free(split);
+
+  TinySegmenter *from_file = tinysegmenter_from_path(argv[1]);
+  CHECK(from_file != NULL);
+  print_words(from_file, "東京都");
+  tinysegmenter_free(from_file);
+
+  CHECK(tinysegmenter_from_path("/nonexistent/model") == NULL);
+  CHECK(tinysegmenter_last_error() != NULL);
+  CHECK(tinysegmenter_from_bytes((const uint8_t *)"BIAS\tx\n", 7) == NULL);
+
+  tinysegmenter_free(NULL);
+  tinysegmenter_segments_free(NULL);
+  puts("ok");
+  return 0;
+}
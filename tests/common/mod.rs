@@ -0,0 +1,18 @@
+//! Fixtures shared by the integration tests.
+#![allow(dead_code)]
+
+use tinysegmenter::corpus::Annotation;
+use tinysegmenter::eval::read_segmented;
+
+/// Sentences the built-in model partly missegments.
+pub fn texts() -> Vec<String> {
+    ["コンピューターの使い方がよくわかりません。", "私の名前は西村です", "経済対策を発表した。"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+/// Fully annotated sentences from space-separated words, one sentence per line.
+pub fn annotated(text: &str) -> Vec<Annotation> {
+    read_segmented(text).iter().map(|words| Annotation::from_words(words)).collect()
+}
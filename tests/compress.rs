@@ -0,0 +1,111 @@
+//! Pruning, quantization and the quantized file format.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use tinysegmenter::compress::{generate_packed_module, parse_any, prune};
+use tinysegmenter::eval::evaluate;
+use tinysegmenter::{Model, QuantizedModel, Segmenter};
+
+const REFERENCE: &str = include_str!("golden/reference.tsv");
+
+/// The golden corpus, which the full built-in model segments exactly.
+fn golden() -> Vec<Vec<String>> {
+    REFERENCE.lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.split('\t').skip(1).map(String::from).collect())
+        .collect()
+}
+
+fn model() -> Model {
+    Model::parse(&format!("BIAS\t-10\nUW1\tあ\t-300\nUW1\tい\t299\nUW1\tう\t300\nUW1\tえ\t{}\nUC1\tH\t5\n", i32::MIN)).unwrap()
+}
+
+#[test]
+fn prune_drops_exactly_the_weights_below_the_threshold() {
+    let pruned = prune(&model(), 300, None);
+    let mut kept: Vec<(&str, i32)> = pruned.tables["UW1"].iter().map(|(k, w)| (k.as_str(), *w)).collect();
+    kept.sort();
+    assert_eq!(kept, [("あ", -300), ("う", 300), ("え", i32::MIN)]);
+    assert!(pruned.tables["UC1"].is_empty());
+    assert_eq!(pruned.bias, -10);
+
+    let top = prune(&model(), 0, Some(2));
+    let mut kept: Vec<&str> = top.tables["UW1"].keys().map(String::as_str).collect();
+    kept.sort();
+    assert_eq!(kept, ["あ", "え"]);
+    assert_eq!(prune(&model(), -1, None), model());
+}
+
+#[test]
+fn quantized_files_round_trip() {
+    let full = Model::builtin();
+    let q8 = QuantizedModel::<i8>::quantize(&full);
+    let text = q8.to_native();
+    assert!(text.starts_with("QUANT\ti8\n"));
+    assert_eq!(QuantizedModel::<i8>::parse(&text).unwrap(), q8);
+    assert_eq!(parse_any(&text).unwrap(), q8.dequantize());
+    assert!(QuantizedModel::<i16>::parse(&text).is_err());
+
+    let q16 = QuantizedModel::<i16>::quantize(&full);
+    assert_eq!(parse_any(&q16.to_native()).unwrap(), q16.dequantize());
+}
+
+#[test]
+fn weights_outside_the_quantized_range_are_rejected() {
+    for weight in ["128", "-128", "2147483647", "-2147483648"] {
+        let text = format!("QUANT\ti8\nBIAS\t0\nSCALE\tUW4\t1\nUW4\tあ\t{}\n", weight);
+        assert!(QuantizedModel::<i8>::parse(&text).is_err(), "{}", weight);
+    }
+    let text = "QUANT\ti8\nBIAS\t0\nSCALE\tUW4\t1\nUW4\tあ\t-127\n";
+    assert!(QuantizedModel::<i8>::parse(text).is_ok());
+}
+
+#[test]
+fn quantized_models_segment_the_golden_corpus_like_the_full_model() {
+    let corpus = golden();
+    let full = Model::builtin();
+    assert_eq!(evaluate(&Segmenter::with_model(full.clone()), &corpus).f1(), 1.0);
+    // The built-in weights fit in i16, so nothing is lost.
+    let q16 = QuantizedModel::<i16>::quantize(&full);
+    assert_eq!(evaluate(&Segmenter::with_model(q16), &corpus).f1(), 1.0);
+    let q8 = QuantizedModel::<i8>::quantize(&full);
+    let f1 = evaluate(&Segmenter::with_model(q8), &corpus).f1();
+    assert!(f1 >= 0.999, "i8 F1 {}", f1);
+}
+
+#[test]
+fn packed_modules_hold_each_table_as_features_and_weights() {
+    let text = "QUANT\ti8\nTEMPLATE\tUW4\tW1\nBIAS\t-3\nSCALE\tUW4\t2.5\nUW4\tい\t-1\nUW4\tあ\t3\n";
+    let model = QuantizedModel::<i8>::parse(text).unwrap();
+    let module = generate_packed_module(&model, "test.model");
+    assert!(module.contains("pub static TABLES: &[(&str, f32, &str, &[i8])] = &[\n    (\"UW4\", 2.5, \"あ\\tい\", &[3, -1]),\n];\n"), "{}", module);
+    let packed = QuantizedModel::<i8>::from_packed(&[("UW4", "W1")], -3, &[("UW4", 2.5, "あ\tい", &[3, -1])]);
+    assert_eq!(packed, model);
+}
+
+/// Rewrites `path` with `generated` under `UPDATE_DEFAULT_MODEL`, then fails
+/// unless they match.
+fn assert_generated(path: &Path, generated: &str) {
+    if env::var_os("UPDATE_DEFAULT_MODEL").is_some() {
+        fs::write(path, generated).unwrap();
+    }
+    let current = fs::read_to_string(path).unwrap();
+    assert!(current == generated, "{} is out of date; rerun with UPDATE_DEFAULT_MODEL=1", path.display());
+}
+
+#[test]
+fn small_model_is_generated_from_the_default_model() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let q8 = QuantizedModel::<i8>::quantize(&Model::builtin());
+    assert_generated(&root.join("models/default.q8.model"), &q8.to_native());
+    assert_generated(&root.join("src/small_model.rs"), &generate_packed_module(&q8, "models/default.q8.model"));
+}
+
+#[cfg(feature = "small-model")]
+#[test]
+fn small_builtin_model_is_the_quantized_file() {
+    let text = include_str!("../models/default.q8.model");
+    assert_eq!(Model::builtin_small(), parse_any(text).unwrap());
+}
@@ -0,0 +1,94 @@
+//! Golden conformance tests against the reference JavaScript TinySegmenter.
+//!
+//! `golden/reference.tsv` holds one sentence per line followed by the words the
+//! reference implementation produced for it, all tab-separated. See
+//! `golden/README.md` for how the file is regenerated.
+
+use tinysegmenter::{tokenize, Segmenter};
+
+const REFERENCE: &str = include_str!("golden/reference.tsv");
+
+/// How many divergent sentences to print before summarising the rest.
+const MAX_REPORTED: usize = 20;
+
+fn reference_cases() -> Vec<(usize, &'static str, Vec<&'static str>)> {
+    REFERENCE.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| {
+            let mut fields = line.split('\t');
+            let input = fields.next().unwrap();
+            (i + 1, input, fields.collect())
+        })
+        .collect()
+}
+
+/// Character offsets at which `words` start a new word.
+fn boundary_offsets<S: AsRef<str>>(words: &[S]) -> Vec<usize> {
+    let mut offset = 0;
+    let mut offsets = Vec::new();
+    for word in words.iter().take(words.len().saturating_sub(1)) {
+        offset += word.as_ref().chars().count();
+        offsets.push(offset);
+    }
+    offsets
+}
+
+fn render_diff(line: usize, input: &str, expected: &[&str], actual: &[String]) -> String {
+    let expected_bounds = boundary_offsets(expected);
+    let actual_bounds = boundary_offsets(actual);
+    let missing: Vec<String> = expected_bounds.iter().filter(|b| !actual_bounds.contains(b)).map(|b| b.to_string()).collect();
+    let extra: Vec<String> = actual_bounds.iter().filter(|b| !expected_bounds.contains(b)).map(|b| b.to_string()).collect();
+    let none = || String::from("-");
+    format!(
+        "line {}: {}\n  reference: {}\n  tokenize:  {}\n  missing boundaries at {}; extra boundaries at {}\n",
+        line,
+        input,
+        expected.join(" | "),
+        actual.join(" | "),
+        if missing.is_empty() { none() } else { missing.join(", ") },
+        if extra.is_empty() { none() } else { extra.join(", ") },
+    )
+}
+
+#[test]
+fn corpus_is_large_enough() {
+    assert!(reference_cases().len() >= 4000);
+}
+
+#[test]
+fn segmentations_match_reference() {
+    let segmenter = Segmenter::new();
+    let cases = reference_cases();
+    let mut diffs = Vec::new();
+    for (line, input, expected) in &cases {
+        let actual = segmenter.segment(input);
+        if actual != *expected {
+            diffs.push(render_diff(*line, input, expected, &actual));
+        }
+    }
+
+    if !diffs.is_empty() {
+        let mut report = format!("{} of {} sentences diverge from the reference:\n\n", diffs.len(), cases.len());
+        for diff in diffs.iter().take(MAX_REPORTED) {
+            report.push_str(diff);
+        }
+        if diffs.len() > MAX_REPORTED {
+            report.push_str(&format!("... and {} more\n", diffs.len() - MAX_REPORTED));
+        }
+        panic!("{}", report);
+    }
+}
+
+#[test]
+fn tokenize_joins_reference_words_with_spaces() {
+    for (line, input, expected) in reference_cases().iter().take(50) {
+        assert_eq!(tokenize(input), expected.join(" "), "line {}", line);
+    }
+}
+
+#[test]
+fn empty_input_yields_no_words() {
+    assert!(Segmenter::new().segment("").is_empty());
+    assert_eq!(tokenize(""), "");
+}
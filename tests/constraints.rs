@@ -0,0 +1,82 @@
+//! Decisions fixed before decoding by run grouping.
+
+use tinysegmenter::{DecodeMode, RunGrouping, Segmenter, SegmenterConfig};
+
+fn grouped(runs: RunGrouping, threshold: i32, decode: DecodeMode) -> Segmenter {
+    Segmenter::new().with_config(SegmenterConfig { runs, threshold, decode, ..Default::default() })
+}
+
+#[test]
+fn no_grouping_by_default() {
+    let plain = Segmenter::new();
+    let none = grouped(RunGrouping::default(), 0, DecodeMode::Greedy);
+    for text in ["iPhone15を買った", "コンピューターの使い方"] {
+        assert_eq!(none.segment(text), plain.segment(text));
+    }
+}
+
+#[test]
+fn runs_stay_whole_whatever_the_scores() {
+    // A threshold this low splits every gap the rules leave open.
+    let katakana = RunGrouping { katakana: true, ..Default::default() };
+    for decode in [DecodeMode::Greedy, DecodeMode::Viterbi] {
+        let segmenter = grouped(katakana, -1_000_000, decode);
+        assert_eq!(segmenter.segment("コンピューターの"), ["コンピューター", "の"]);
+    }
+    let words = grouped(katakana, -1_000_000, DecodeMode::Greedy).segment_nbest("コンピューター", 3);
+    assert_eq!(words.len(), 1);
+}
+
+#[test]
+fn run_edges_are_left_to_the_model() {
+    let katakana = RunGrouping { katakana: true, ..Default::default() };
+    let joined = grouped(katakana, 1_000_000, DecodeMode::Greedy);
+    assert_eq!(joined.segment("コンピューターの"), ["コンピューターの"]);
+}
+
+#[test]
+fn lengthened_hiragana_is_not_a_katakana_run() {
+    let plain = Segmenter::new();
+    let katakana = grouped(RunGrouping { katakana: true, ..Default::default() }, 0, DecodeMode::Greedy);
+    for text in ["すごーい", "らーめん", "ちょーうまい", "らーメン"] {
+        assert_eq!(katakana.segment(text), plain.segment(text), "{}", text);
+    }
+    // A mark after a real katakana character continues its run.
+    let split = grouped(RunGrouping { katakana: true, ..Default::default() }, -1_000_000, DecodeMode::Greedy);
+    assert_eq!(split.segment("すごーいコーヒー"), ["す", "ご", "ー", "い", "コーヒー"]);
+}
+
+#[test]
+fn each_type_is_configured_separately() {
+    let text = "iPhone15を買った";
+    let separate = RunGrouping { alphabet: true, digits: true, ..Default::default() };
+    assert_eq!(&grouped(separate, 0, DecodeMode::Greedy).segment(text)[..2], ["iPhone", "15"]);
+    let mixed = RunGrouping { alphanumeric: true, ..Default::default() };
+    assert_eq!(grouped(mixed, 0, DecodeMode::Greedy).segment(text)[0], "iPhone15");
+    let digits = RunGrouping { digits: true, ..Default::default() };
+    assert_eq!(grouped(digits, -1_000_000, DecodeMode::Greedy).segment("第15回"), ["第", "15", "回"]);
+}
+
+#[test]
+fn per_call_configs_bring_their_own_grouping() {
+    let split = SegmenterConfig { threshold: -1_000_000, ..Default::default() };
+    let katakana = SegmenterConfig { runs: RunGrouping { katakana: true, ..Default::default() }, ..split };
+    let segmenter = Segmenter::new().with_config(split);
+    assert_eq!(segmenter.segment_with("コンピューターの", &katakana), ["コンピューター", "の"]);
+    let grouped = Segmenter::new().with_config(katakana);
+    assert_eq!(grouped.segment_with("カメラ", &split), ["カ", "メ", "ラ"]);
+}
+
+#[test]
+fn explanations_report_forced_decisions() {
+    let segmenter = grouped(RunGrouping::all(), -1_000_000, DecodeMode::Greedy);
+    let gaps = segmenter.explain("ABC12");
+    assert!(gaps.iter().all(|g| !g.boundary && g.score > -1_000_000));
+}
+
+#[test]
+fn grouping_names() {
+    assert_eq!("all".parse(), Ok(RunGrouping::all()));
+    assert_eq!("katakana, digits".parse(), Ok(RunGrouping { katakana: true, digits: true, ..Default::default() }));
+    assert!("kanji".parse::<RunGrouping>().is_err());
+}
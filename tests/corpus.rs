@@ -0,0 +1,70 @@
+//! Readers for annotated corpora.
+
+use tinysegmenter::corpus::{read, Format};
+
+fn words(sentences: &[&[&str]]) -> Vec<Vec<String>> {
+    sentences.iter().map(|s| s.iter().map(|w| w.to_string()).collect()).collect()
+}
+
+#[test]
+fn reads_space_segmented_text() {
+    let corpus = read("私 の 名前\n\n西村 です\n", Format::Segmented).unwrap();
+    assert_eq!(corpus, words(&[&["私", "の", "名前"], &["西村", "です"]]));
+}
+
+#[test]
+fn reads_mecab_ipadic_output() {
+    let text = "\
+私\t名詞,代名詞,一般,*,*,*,私,ワタシ,ワタシ
+の\t助詞,連体化,*,*,*,*,の,ノ,ノ
+名前\t名詞,一般,*,*,*,*,名前,ナマエ,ナマエ
+EOS
+西村\t名詞,固有名詞,人名,姓,*,*,西村,ニシムラ,ニシムラ
+です\t助動詞,*,*,*,特殊・デス,基本形,です,デス,デス
+EOS
+";
+    let corpus = read(text, Format::Mecab).unwrap();
+    assert_eq!(corpus, words(&[&["私", "の", "名前"], &["西村", "です"]]));
+    assert!(read("私 名詞\nEOS\n", Format::Mecab).is_err());
+}
+
+#[test]
+fn reads_unidic_tsv() {
+    let text = "私\tワタクシ\t私\t代名詞\nの\tノ\tの\t助詞-格助詞\n\n西村\tニシムラ\t西村\t名詞-固有名詞-人名-姓\nEOS\n";
+    let corpus = read(text, Format::Unidic).unwrap();
+    assert_eq!(corpus, words(&[&["私", "の"], &["西村"]]));
+}
+
+#[test]
+fn reads_conllu_forms() {
+    let text = "\
+# sent_id = train-s1
+# text = 私の名前は西村です
+1\t私\t私\tPRON\t_\t_\t3\tnmod\t_\tSpaceAfter=No
+2\tの\tの\tADP\t_\t_\t1\tcase\t_\tSpaceAfter=No
+3\t名前\t名前\tNOUN\t_\t_\t5\tnsubj\t_\tSpaceAfter=No
+4\tは\tは\tADP\t_\t_\t3\tcase\t_\tSpaceAfter=No
+5-6\t西村です\t_\t_\t_\t_\t_\t_\t_\tSpaceAfter=No
+5\t西村\t西村\tPROPN\t_\t_\t0\troot\t_\tSpaceAfter=No
+6\tです\tです\tAUX\t_\t_\t5\tcop\t_\tSpaceAfter=No
+
+";
+    let corpus = read(text, Format::Conllu).unwrap();
+    assert_eq!(corpus, words(&[&["私", "の", "名前", "は", "西村", "です"]]));
+    assert!(read("1\t私\t私\n", Format::Conllu).is_err());
+}
+
+#[test]
+fn reads_kytea_full_annotation() {
+    let text = "私/代名詞/わたし の/助詞/の 名前/名詞/なまえ\n1\\/2/名詞/にぶんのいち です/助動詞/です\n";
+    let corpus = read(text, Format::Kytea).unwrap();
+    assert_eq!(corpus, words(&[&["私", "の", "名前"], &["1/2", "です"]]));
+}
+
+#[test]
+fn format_names_parse() {
+    for name in Format::NAMES {
+        assert!(name.parse::<Format>().is_ok(), "{}", name);
+    }
+    assert!("partial".parse::<Format>().is_err());
+}
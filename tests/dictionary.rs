@@ -0,0 +1,47 @@
+//! Dictionary features.
+
+mod common;
+
+use tinysegmenter::train::{Algorithm, OnlineLearner};
+use tinysegmenter::{Dictionary, Model, Segmenter};
+
+fn dictionary_model() -> Model {
+    Model::parse("TEMPLATE\tDICT\tD\nTEMPLATE\tUC4\tC1\nBIAS\t0\n").unwrap()
+}
+
+#[test]
+fn word_lists_are_read_one_word_per_line() {
+    let dictionary = Dictionary::parse("# place names\n東京\t名詞\n\n京都\n");
+    assert_eq!(dictionary.len(), 2);
+    assert!(dictionary.contains("東京"));
+    assert!(!dictionary.contains("名詞"));
+}
+
+#[test]
+fn features_report_words_ending_starting_and_spanning_each_gap() {
+    let segmenter = Segmenter::with_model(dictionary_model()).with_dictionary(Dictionary::new(["東京", "京都"]));
+    let keys: Vec<Vec<String>> = segmenter.explain("東京都").iter()
+        .map(|gap| gap.contributions.iter().filter(|c| c.template == "DICT").map(|c| c.key()).collect())
+        .collect();
+    assert_eq!(keys, [vec!["DICT:I2", "DICT:R2"], vec!["DICT:I2", "DICT:L2"]]);
+
+    // Without a dictionary the template is inert.
+    let plain = Segmenter::with_model(dictionary_model());
+    assert!(plain.explain("東京都").iter().all(|gap| gap.contributions.iter().all(|c| c.template != "DICT")));
+}
+
+#[test]
+fn learned_dictionary_weights_generalise_to_unseen_sentences() {
+    let dictionary = Dictionary::new(["東京", "大阪", "名古屋", "に", "へ", "行く", "来る"]);
+    let corpus = common::annotated("東京 に 行く\n大阪 へ 来る\n");
+    let mut learner = OnlineLearner::new(&dictionary_model(), Algorithm::default()).with_dictionary(dictionary.clone());
+    for _ in 0..5 {
+        learner.epoch(&corpus);
+    }
+    let model = learner.model();
+    assert_eq!(model.templates.iter().filter(|t| t.is_dictionary()).count(), 1);
+    assert!(model.tables["DICT"]["R2"] > 0);
+
+    let segmenter = Segmenter::with_model(Model::parse(&model.to_native()).unwrap()).with_dictionary(dictionary);
+    assert_eq!(segmenter.segment("名古屋に来る"), ["名古屋", "に", "来る"]);
+}
@@ -0,0 +1,38 @@
+//! Per-gap explanations and their renderings.
+
+use tinysegmenter::explain::{render_json, render_table};
+use tinysegmenter::{Model, Segmenter};
+
+#[test]
+fn known_gap_lists_its_features_and_weights() {
+    let model = Model::parse("BIAS\t-100\nBW2\t日本\t400\nUC4\tH\t-50\n").unwrap();
+    let gaps = Segmenter::with_model(model).explain("日本");
+    assert_eq!(gaps.len(), 1);
+    let gap = &gaps[0];
+    assert_eq!((gap.position, gap.left.as_str(), gap.right.as_str()), (1, "日", "本"));
+    assert_eq!(gap.bias, -100);
+    let weighted: Vec<(String, i32)> = gap.contributions.iter().filter(|c| c.weight != 0).map(|c| (c.key(), c.weight)).collect();
+    assert_eq!(weighted, [(String::from("BW2:日本"), 400), (String::from("UC4:H"), -50)]);
+    assert_eq!(gap.score, 250);
+    assert!(gap.boundary);
+
+    let table = render_table(&gaps);
+    assert!(table.starts_with("gap 1 (日|本): score 250 -> boundary\n"), "{}", table);
+    assert!(table.contains("\n       400      300  BW2:日本\n"), "{}", table);
+    let json = render_json(&gaps);
+    assert!(json.contains("{\"template\":\"BW2\",\"feature\":\"日本\",\"weight\":400,\"total\":300}"), "{}", json);
+}
+
+#[test]
+fn contributions_sum_to_the_gap_score() {
+    let segmenter = Segmenter::new();
+    let text = "私の名前は中野です。コンピューターを使う";
+    let gaps = segmenter.explain(text);
+    assert_eq!(gaps.len(), text.chars().count() - 1);
+    for gap in &gaps {
+        let sum: i32 = gap.bias + gap.contributions.iter().map(|c| c.weight).sum::<i32>();
+        assert_eq!(sum, gap.score, "gap {}", gap.position);
+        assert_eq!(gap.contributions.last().unwrap().total, gap.score);
+        assert_eq!(gap.boundary, gap.score > 0);
+    }
+}
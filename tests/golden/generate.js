@@ -0,0 +1,19 @@
+// Segments every line of corpus.txt with the reference implementation and
+// writes reference.tsv: the input sentence followed by one column per word.
+//
+//   node tests/golden/generate.js > tests/golden/reference.tsv
+
+var fs = require("fs");
+var path = require("path");
+var TinySegmenter = require("./tinysegmenter.js");
+
+var segmenter = new TinySegmenter();
+var corpus = fs.readFileSync(path.join(__dirname, "corpus.txt"), "utf8");
+var out = [];
+corpus.split("\n").forEach(function(line) {
+  if (line === "") {
+    return;
+  }
+  out.push([line].concat(segmenter.segment(line)).join("\t"));
+});
+process.stdout.write(out.join("\n") + "\n");
@@ -0,0 +1,214 @@
+// Builds corpus.txt: hand-written sentences followed by synthetic sentences
+// drawn from a fixed vocabulary with a seeded generator, so the output is
+// identical on every run.
+//
+//   node tests/golden/make_corpus.js > tests/golden/corpus.txt
+
+var SENTENCES = [
+  "私の名前は西村です",
+  "今日はいい天気ですね。",
+  "東京都に行きました",
+  "彼は昨日東京から大阪へ新幹線で移動した。",
+  "日本語の文章を単語に分割するのは簡単ではない。",
+  "この本はとても面白かったので、友達にも勧めました。",
+  "来年の四月から大学院に進学する予定です。",
+  "政府は十二月に新しい経済対策を発表した。",
+  "コンピューターの使い方がよくわかりません。",
+  "iPhone15を買いに渋谷のアップルストアへ行った。",
+  "ＡＢＣ株式会社は２０２４年に設立された。",
+  "「ありがとう」と彼女は小さな声で言った。",
+  "雨が降っているので、傘を持って行きましょう。",
+  "駅前の喫茶店でコーヒーを飲みながら新聞を読んだ。",
+  "明日の会議は午後三時から始まります。",
+  "子供たちは公園でサッカーをして遊んでいる。",
+  "このプログラムはRustで書かれています。",
+  "京都の寺を見学するために多くの観光客が訪れる。",
+  "彼女はピアノを弾くのがとても上手だ。",
+  "一九六四年に東京オリンピックが開催された。",
+  "スマートフォンの普及によって生活が大きく変わった。",
+  "ご飯を食べてから散歩に出かけた。",
+  "電車が遅れたので、会社に遅刻してしまった。",
+  "その問題について、委員会で議論が続いている。",
+  "ｶﾀｶﾅの半角文字も正しく扱えるだろうか。",
+  "価格は1,980円（税込）です。",
+  "山田さんは毎朝六時に起きてジョギングをする。",
+  "北海道の冬はとても寒く、雪がたくさん降る。",
+  "私たちは週末に映画を見に行く予定だ。",
+  "新しいデータベースのパフォーマンスを測定した。",
+  "彼の説明によると、原因はまだわかっていないらしい。",
+  "社会党と自民党の連立政権が誕生した。",
+  "大阪府の人口は約八百八十万人である。",
+  "メールアドレスはinfo@example.comまでお送りください。",
+  "先生に質問したら、丁寧に教えてくれた。",
+  "夏休みには家族で沖縄へ旅行する。",
+  "このレストランのラーメンは本当においしい。",
+  "第二次世界大戦が終わったのは一九四五年だ。",
+  "毎日新聞の記者が現地で取材を行った。",
+  "できるだけ早く返事をください。",
+  "ウェブサイトのデザインをリニューアルしました。",
+  "彼らは朝から晩まで働き続けた。",
+  "米国の大統領選挙が十一月に行われる。",
+  "猫が窓の外をじっと見つめている。",
+  "この町には古い建物がたくさん残っている。",
+  "インターネットで調べれば、すぐに答えが見つかる。",
+  "会議室の予約は午前十時までにお願いします。",
+  "トヨタ自動車の新型モデルが発表された。",
+  "森の中を歩いていると、鳥の声が聞こえてきた。",
+  "ＮＨＫのニュースによれば、地震の被害は小さかった。",
+  "彼女は英語とフランス語を話すことができる。",
+  "それはまるで夢のような出来事だった。",
+  "日本の伝統文化を海外に紹介したい。",
+  "明日は晴れのち曇り、所により雨が降るでしょう。",
+  "僕はまだ何も決めていない。",
+  "ご注文の商品は三日以内に発送いたします。",
+  "この薬は一日三回、食後に飲んでください。",
+  "どうしてそんなことを言うのですか？",
+  "すごい！本当に優勝したんだ！",
+  "ＰＣのメモリを１６ＧＢに増設した。",
+  "東京大学の研究チームが新しい素材を開発した。",
+  "川の水がきれいになって、魚が戻ってきた。",
+  "図書館で借りた本を返すのを忘れていた。",
+  "ロボットが人間の仕事を代わりに行う時代が来た。",
+  "お客様、こちらのお席へどうぞ。",
+  "昨夜から熱があって、今日は学校を休んだ。",
+  "彼は一人で山に登るのが好きだ。",
+  "ケーキを作るために砂糖と卵を買ってきた。",
+  "そのニュースを聞いて、みんな驚いた。",
+  "マイクロソフトとグーグルが提携を発表した。",
+  "Version 2.0 では検索機能が改善された。",
+  "参加者は全部で百二十三人だった。",
+  "秋になると、山の木々が赤や黄色に色づく。",
+  "彼女の笑顔を見ると、元気が出る。",
+  "もし時間があれば、手伝ってもらえませんか。",
+  "新聞によると、景気は回復しつつあるという。",
+  "ゲームばかりしていないで、宿題をしなさい。",
+  "鈴木一郎選手がホームランを打った。",
+  "この道をまっすぐ行くと、右手に郵便局があります。",
+  "富士山は日本で一番高い山です。",
+  "わたしはきのうともだちとえいがをみました。",
+  "アイスクリームとチョコレートとクッキーを食べた。",
+  "午前９時３０分に羽田空港を出発する。",
+  "国際会議は来月ジュネーブで開かれる予定だ。",
+  "彼は毎晩遅くまでテレビを見ている。",
+  "パソコンが突然動かなくなって困った。",
+  "駅までバスで十五分ほどかかります。",
+  "私は日本人ですが、日本の歴史にはあまり詳しくない。",
+  "子どもの頃、よく祖母の家に遊びに行った。",
+  "ソフトウェアのバグを修正するのに三日かかった。",
+  "窓を開けると、涼しい風が入ってきた。",
+  "この映画は世界中で大ヒットした。",
+  "お忙しいところ恐れ入りますが、ご確認をお願いいたします。",
+  "大きな地震の後、多くの人が避難所で生活している。",
+  "彼の作った料理はどれもおいしかった。",
+  "ＪＲ東日本は運賃の値上げを発表した。",
+  "本日は晴天なり。",
+  "吾輩は猫である。名前はまだ無い。",
+  "国境の長いトンネルを抜けると雪国であった。",
+  "すもももももももものうち",
+];
+
+var SUBJECTS = [
+  "私", "僕", "彼", "彼女", "私たち", "彼ら", "山田さん", "田中先生", "学生たち",
+  "社長", "子供", "母", "父", "友達", "政府", "会社", "委員会", "研究者", "記者",
+  "佐藤", "鈴木さん", "市長", "警察", "チーム", "ユーザー", "エンジニア", "担当者",
+];
+var TOPICS = ["は", "が", "も"];
+var TIMES = [
+  "今日", "昨日", "明日", "毎朝", "去年", "来年", "先週", "今月", "午後", "夜",
+  "三月", "十二月", "二〇二三年", "2024年", "１０月", "朝早く", "週末", "最近",
+];
+var PLACES = [
+  "東京", "大阪", "京都", "北海道", "沖縄", "名古屋", "横浜", "福岡", "駅前", "学校",
+  "図書館", "公園", "病院", "会社", "大学", "空港", "スーパー", "レストラン", "ホテル",
+  "コンビニ", "東京都", "神奈川県", "渋谷区", "アメリカ", "フランス", "中国",
+];
+var OBJECTS = [
+  "本", "新聞", "手紙", "ご飯", "水", "コーヒー", "お茶", "パン", "写真", "映画",
+  "音楽", "宿題", "仕事", "資料", "報告書", "データ", "プログラム", "ソフトウェア",
+  "日本語", "英語", "料理", "車", "自転車", "パソコン", "スマートフォン", "メール",
+  "ニュース", "問題", "計画", "予算", "法案", "製品", "サービス", "ｶﾒﾗ", "ﾃﾞｰﾀ",
+];
+var VERBS = [
+  ["読み", "読む", "読んだ", "読んで"], ["書き", "書く", "書いた", "書いて"],
+  ["食べ", "食べる", "食べた", "食べて"], ["飲み", "飲む", "飲んだ", "飲んで"],
+  ["見", "見る", "見た", "見て"], ["買い", "買う", "買った", "買って"],
+  ["作り", "作る", "作った", "作って"], ["送り", "送る", "送った", "送って"],
+  ["使い", "使う", "使った", "使って"], ["調べ", "調べる", "調べた", "調べて"],
+  ["発表し", "発表する", "発表した", "発表して"], ["説明し", "説明する", "説明した", "説明して"],
+  ["開発し", "開発する", "開発した", "開発して"], ["確認し", "確認する", "確認した", "確認して"],
+  ["勉強し", "勉強する", "勉強した", "勉強して"], ["検討し", "検討する", "検討した", "検討して"],
+];
+var MOVES = [
+  ["行き", "行く", "行った"], ["来", "来る", "来た"], ["帰り", "帰る", "帰った"],
+  ["向かい", "向かう", "向かった"], ["戻り", "戻る", "戻った"],
+];
+var ADJECTIVES = [
+  "大きい", "小さい", "新しい", "古い", "高い", "安い", "面白い", "難しい", "簡単な",
+  "静かな", "有名な", "きれいな", "便利な", "重要な", "美しい", "早い",
+];
+var NOUNS = [
+  "建物", "部屋", "店", "町", "道", "花", "犬", "猫", "人", "国", "会議", "事件",
+  "技術", "システム", "デザイン", "アイデア", "プロジェクト", "イベント", "サイト",
+];
+var NUMBERS = [
+  "一", "二", "三", "五", "十", "百", "千", "1", "2", "10", "100", "2,000", "３", "１５", "二十",
+];
+var COUNTERS = ["人", "冊", "本", "個", "回", "円", "台", "枚", "時間", "分", "キロ", "％"];
+var LATIN = ["iPhone", "Rust", "API", "USB", "Wi-Fi", "ＰＣ", "ＮＨＫ", "AI", "OS", "Google", "ＩＴ"];
+var ENDINGS = ["。", "。", "。", "！", "？", "", "．"];
+var CONNECTIVES = ["ので、", "が、", "けれども、", "から、", "し、"];
+
+// mulberry32
+var seed = 20081013;
+function random() {
+  seed = (seed + 0x6D2B79F5) | 0;
+  var t = seed;
+  t = Math.imul(t ^ (t >>> 15), t | 1);
+  t ^= t + Math.imul(t ^ (t >>> 7), t | 61);
+  return ((t ^ (t >>> 14)) >>> 0) / 4294967296;
+}
+function pick(list) {
+  return list[Math.floor(random() * list.length)];
+}
+
+function clause() {
+  var verb = pick(VERBS);
+  var move = pick(MOVES);
+  switch (Math.floor(random() * 9)) {
+    case 0: return pick(SUBJECTS) + pick(TOPICS) + pick(TIMES) + pick(OBJECTS) + "を" + verb[2];
+    case 1: return pick(TIMES) + pick(PLACES) + "へ" + move[2];
+    case 2: return pick(SUBJECTS) + pick(TOPICS) + pick(PLACES) + "で" + pick(OBJECTS) + "を" + verb[0] + "ました";
+    case 3: return pick(PLACES) + "の" + pick(NOUNS) + "は" + pick(ADJECTIVES) + pick(["です", "だ", "そうだ", "らしい"]);
+    case 4: return pick(SUBJECTS) + "に" + pick(OBJECTS) + "を" + verb[3] + "もらった";
+    case 5: return pick(NUMBERS) + pick(COUNTERS) + "の" + pick(OBJECTS) + "を" + verb[2];
+    case 6: return pick(LATIN) + "で" + pick(OBJECTS) + "を" + verb[1] + "ことができる";
+    case 7: return pick(SUBJECTS) + pick(TOPICS) + pick(OBJECTS) + "について" + verb[0] + "たいと言っている";
+    default: return pick(ADJECTIVES) + pick(NOUNS) + "が" + pick(PLACES) + "にある";
+  }
+}
+
+function sentence() {
+  var s = clause();
+  if (random() < 0.35) {
+    s += pick(CONNECTIVES) + clause();
+  }
+  if (random() < 0.1) {
+    s = "「" + s + "」と" + pick(SUBJECTS) + "は言った";
+  }
+  return s + pick(ENDINGS);
+}
+
+var seen = {};
+var lines = [];
+SENTENCES.forEach(function(s) {
+  seen[s] = true;
+  lines.push(s);
+});
+while (lines.length < 4000) {
+  var s = sentence();
+  if (!seen[s]) {
+    seen[s] = true;
+    lines.push(s);
+  }
+}
+process.stdout.write(lines.join("\n") + "\n");
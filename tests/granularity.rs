@@ -0,0 +1,71 @@
+//! Short, middle and long units.
+
+use tinysegmenter::{CompoundConfig, Granularity, Segmenter, SegmenterConfig, Unit};
+
+fn with_margins(middle_margin: i32, long_margin: i32) -> Segmenter {
+    let compounds = CompoundConfig { middle_margin, long_margin };
+    Segmenter::new().with_config(SegmenterConfig { compounds, ..Default::default() })
+}
+
+fn levels(segmenter: &Segmenter, text: &str) -> [Vec<String>; 3] {
+    let units = segmenter.segment_units(text);
+    [Granularity::Short, Granularity::Middle, Granularity::Long].map(|g| Unit::words(&units, g))
+}
+
+#[test]
+fn short_units_are_the_segmenter_words() {
+    let segmenter = Segmenter::new();
+    for text in ["東京スカイツリーに行った", "経済対策を発表した。", "私の名前は中野です"] {
+        assert_eq!(levels(&segmenter, text)[0], segmenter.segment(text));
+    }
+    assert!(segmenter.segment_units("").is_empty());
+}
+
+#[test]
+fn weak_splits_merge_by_script() {
+    let segmenter = Segmenter::new();
+    // Same script: merged from the middle level up.
+    assert_eq!(levels(&segmenter, "経済対策を発表した。")[1][0], "経済対策");
+    // Kanji and katakana: merged only in long units.
+    let [short, middle, long] = levels(&segmenter, "東京スカイツリーに行った");
+    assert_eq!(&short[..2], ["東京", "スカイツリー"]);
+    assert_eq!(middle, short);
+    assert_eq!(long[0], "東京スカイツリー");
+    // Hiragana is never compounded.
+    let [short, _, long] = levels(&segmenter, "私の名前は中野です");
+    assert_eq!(long, short);
+}
+
+#[test]
+fn margins_bound_the_merges() {
+    let text = "国立国会図書館で調べた";
+    let [short, middle, long] = levels(&with_margins(i32::MIN, i32::MIN), text);
+    assert_eq!(middle, short);
+    assert_eq!(long, short);
+    let [_, middle, long] = levels(&with_margins(i32::MAX, i32::MAX), text);
+    assert_eq!(middle[0], "国立国会図書館");
+    assert_eq!(long[0], "国立国会図書館");
+}
+
+#[test]
+fn units_nest() {
+    let units = Segmenter::new().segment_units("国立国会図書館で調べた");
+    let mut start = 0;
+    for unit in &units {
+        assert_eq!(unit.start, start);
+        assert_eq!(unit.surface, unit.parts.iter().map(|p| p.surface.as_str()).collect::<String>());
+        assert_eq!((unit.parts[0].start, unit.parts.last().unwrap().end), (unit.start, unit.end));
+        for part in &unit.parts {
+            assert_eq!(part.surface, part.parts.iter().map(|p| p.surface.as_str()).collect::<String>());
+            assert!(part.parts.iter().all(|p| p.parts.is_empty()));
+        }
+        start = unit.end;
+    }
+}
+
+#[test]
+fn granularity_names() {
+    assert_eq!("middle".parse(), Ok(Granularity::Middle));
+    assert_eq!("C".parse(), Ok(Granularity::Long));
+    assert!("huge".parse::<Granularity>().is_err());
+}
@@ -0,0 +1,44 @@
+//! Template statistics, top weights and feature lookup.
+
+use tinysegmenter::inspect::{lookup, template_stats, top_features, Feature};
+use tinysegmenter::{Model, Segmenter};
+
+fn model() -> Model {
+    Model::parse("BIAS\t-100\nBW2\t日本\t400\nBW2\t本日\t-20\nUC4\tH\t-50\n").unwrap()
+}
+
+#[test]
+fn stats_list_every_template_with_its_range() {
+    let stats = template_stats(&model());
+    let bw2 = stats.iter().find(|s| s.name == "BW2").unwrap();
+    assert_eq!((bw2.entries, bw2.min, bw2.max), (2, Some(-20), Some(400)));
+    let uc4 = stats.iter().find(|s| s.name == "UC4").unwrap();
+    assert_eq!((uc4.entries, uc4.min, uc4.max), (1, Some(-50), Some(-50)));
+    let uw1 = stats.iter().find(|s| s.name == "UW1").unwrap();
+    assert_eq!((uw1.entries, uw1.min, uw1.max), (0, None, None));
+}
+
+#[test]
+fn top_features_split_by_sign() {
+    let (positive, negative) = top_features(&model(), 1);
+    let keys = |features: &[Feature]| -> Vec<(String, i32)> { features.iter().map(|f| (f.key(), f.weight)).collect() };
+    assert_eq!(keys(&positive), vec![(String::from("BW2:日本"), 400)]);
+    assert_eq!(keys(&negative), vec![(String::from("UC4:H"), -50)]);
+}
+
+#[test]
+fn lookup_finds_the_features_of_a_known_gap() {
+    let model = model();
+    assert_eq!(lookup(&model, "BW2:日本"), Some(400));
+    assert_eq!(lookup(&model, "BW2:東京"), None);
+    assert_eq!(lookup(&model, "no colon"), None);
+
+    // Every feature looked up for the gap of 日|本 has the weight `lookup`
+    // reports, and missing ones count as zero.
+    let gap = &Segmenter::with_model(model.clone()).explain("日本")[0];
+    for contribution in &gap.contributions {
+        assert_eq!(contribution.weight, lookup(&model, &contribution.key()).unwrap_or(0), "{}", contribution.key());
+    }
+    let weighted: Vec<String> = gap.contributions.iter().filter(|c| c.weight != 0).map(|c| c.key()).collect();
+    assert_eq!(weighted, ["BW2:日本", "UC4:H"]);
+}
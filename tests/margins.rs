@@ -0,0 +1,40 @@
+//! Per-word margins.
+
+mod common;
+
+use tinysegmenter::{DecodeMode, RunGrouping, Segmenter, SegmenterConfig};
+
+#[test]
+fn margins_are_the_closest_gap_around_each_word() {
+    let segmenter = Segmenter::new();
+    for text in common::texts() {
+        let scores = segmenter.gap_scores(&text);
+        let margins = segmenter.segment_with_margins(&text);
+        let words: Vec<String> = margins.iter().map(|(w, _)| w.clone()).collect();
+        assert_eq!(words, segmenter.segment(&text));
+        let mut start = 0;
+        for (word, margin) in margins {
+            let end = start + word.chars().count();
+            let gaps = (start.max(1)..end.min(scores.len()) + 1).map(|p| scores[p - 1].abs());
+            assert_eq!(margin, gaps.min().unwrap());
+            start = end;
+        }
+    }
+    assert_eq!(segmenter.segment_with_margins("私"), vec![(String::from("私"), i32::MAX)]);
+    assert!(segmenter.segment_with_margins("").is_empty());
+}
+
+#[test]
+fn margins_follow_the_configured_decoder() {
+    let viterbi = Segmenter::new().with_config(SegmenterConfig { decode: DecodeMode::Viterbi, ..Default::default() });
+    let text = "東京都に行きました";
+    let words: Vec<String> = viterbi.segment_with_margins(text).into_iter().map(|(w, _)| w).collect();
+    assert_eq!(words, viterbi.segment(text));
+}
+
+#[test]
+fn forced_gaps_do_not_count() {
+    let grouped = Segmenter::new().with_config(SegmenterConfig { runs: RunGrouping::all(), ..Default::default() });
+    let margins = grouped.segment_with_margins("ABC");
+    assert_eq!(margins, vec![(String::from("ABC"), i32::MAX)]);
+}
@@ -0,0 +1,92 @@
+//! Combining a base model with an in-domain model.
+
+use std::collections::HashMap;
+
+use tinysegmenter::corpus::Annotation;
+use tinysegmenter::merge::{merge, sweep_lambda, Combination, Combined};
+use tinysegmenter::train::{update_model, Algorithm};
+use tinysegmenter::{Model, ModelError, Segmenter, SegmenterConfig, Weights};
+
+fn base() -> Model {
+    Model::parse("BIAS\t-100\nBW2\t日本\t400\nUC4\tH\t-50\n").unwrap()
+}
+
+fn domain() -> Model {
+    Model::parse("BIAS\t100\nBW2\t日本\t-200\nBW2\t東京\t300\n").unwrap()
+}
+
+#[test]
+fn interpolation_weights_each_model() {
+    let half = merge(&base(), &domain(), &Combination::interpolate(0.5)).unwrap();
+    assert_eq!(half.bias, 0);
+    assert_eq!(half.tables["BW2"]["日本"], 100);
+    assert_eq!(half.tables["BW2"]["東京"], 150);
+    assert_eq!(half.tables["UC4"]["H"], -25);
+
+    let none = merge(&base(), &domain(), &Combination::interpolate(0.0)).unwrap();
+    assert_eq!(none.bias, -100);
+    assert!(!none.tables["BW2"].contains_key("東京"));
+}
+
+#[test]
+fn per_template_lambdas_override_the_default() {
+    let templates = HashMap::from([(String::from("BW2"), 1.0)]);
+    let merged = merge(&base(), &domain(), &Combination::Interpolate { lambda: 0.0, templates }).unwrap();
+    assert_eq!(merged.bias, -100);
+    assert_eq!(merged.tables["BW2"]["日本"], -200);
+    assert_eq!(merged.tables["UC4"]["H"], -50);
+}
+
+#[test]
+fn stacking_adds_the_domain_correction() {
+    let stacked = merge(&base(), &domain(), &Combination::Stack).unwrap();
+    assert_eq!(stacked.bias, 0);
+    assert_eq!(stacked.tables["BW2"]["日本"], 200);
+    assert_eq!(stacked.tables["BW2"]["東京"], 300);
+}
+
+#[test]
+fn runtime_combination_matches_merged_model() {
+    let words: Vec<String> = ["よく", "わかり", "ませ", "ん"].iter().map(|w| w.to_string()).collect();
+    let domain = update_model(&Model::builtin(), &[Annotation::from_words(&words)], Algorithm::default(), 3);
+    for combination in [Combination::interpolate(0.3), Combination::Stack] {
+        let merged = Segmenter::with_model(merge(&Model::builtin(), &domain, &combination).unwrap());
+        let combined = Segmenter::with_model(Combined::new(Model::builtin(), &domain, combination).unwrap());
+        for text in ["コンピューターの使い方がよくわかりません。", "私の名前は西村です"] {
+            assert_eq!(combined.segment(text), merged.segment(text));
+        }
+        assert_eq!(combined.model().bias(), merged.model().bias);
+    }
+}
+
+#[test]
+fn differently_defined_templates_cannot_be_combined() {
+    let other = Model::parse("TEMPLATE\tBW2\tW-2 W-1\nBIAS\t0\n").unwrap();
+    assert!(matches!(merge(&base(), &other, &Combination::Stack), Err(ModelError::TemplateMismatch(name)) if name == "BW2"));
+}
+
+#[test]
+fn sweep_scores_every_lambda() {
+    let dev = vec![vec!["私".to_string(), "の".to_string(), "名前".to_string()]];
+    let config = SegmenterConfig::default();
+    let results = sweep_lambda(&Model::builtin(), &Model::builtin(), None, &config, &dev, &[0.0, 0.5, 1.0]).unwrap();
+    assert_eq!(results.iter().map(|(l, _)| *l).collect::<Vec<_>>(), [0.0, 0.5, 1.0]);
+    assert!(results.iter().all(|(_, scores)| scores.f1() == results[0].1.f1()));
+    assert!(results[0].1.f1() > 0.0);
+}
+
+#[test]
+fn sweep_segments_with_the_given_config() {
+    let dev = vec![vec!["私".to_string(), "の".to_string(), "名前".to_string()]];
+    let config = SegmenterConfig { threshold: i32::MAX, ..Default::default() };
+    let results = sweep_lambda(&Model::builtin(), &Model::builtin(), None, &config, &dev, &[0.0, 1.0]).unwrap();
+    assert!(results.iter().all(|(_, scores)| scores.f1() == 0.0));
+}
+
+#[test]
+fn stacked_weights_saturate() {
+    let big = Model::parse("BIAS\t2147483647\nBW2\t日本\t-2147483648\n").unwrap();
+    let stacked = merge(&big, &big, &Combination::Stack).unwrap();
+    assert_eq!(stacked.bias, i32::MAX);
+    assert_eq!(stacked.tables["BW2"]["日本"], i32::MIN);
+}
@@ -0,0 +1,93 @@
+//! Loading, checking and generating models, and scoring with them.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use tinysegmenter::model::{generate_rust_module, ModelError};
+use tinysegmenter::{DecodeMode, Model, Segmenter, SegmenterConfig};
+
+#[test]
+fn extreme_weights_saturate_instead_of_overflowing() {
+    let model = Model::parse("BIAS\t2147483647\nUW3\tあ\t2147483647\nUW4\tい\t2147483647\n").unwrap();
+    for decode in [DecodeMode::Greedy, DecodeMode::Viterbi] {
+        let segmenter = Segmenter::with_model(model.clone()).with_config(SegmenterConfig { decode, ..Default::default() });
+        assert_eq!(segmenter.segment("あい"), ["あ", "い"]);
+        assert_eq!(segmenter.segment_nbest("あい", 2)[0].words, ["あ", "い"]);
+    }
+    let segmenter = Segmenter::with_model(model);
+    assert_eq!(segmenter.explain("あい")[0].score, i32::MAX);
+    assert_eq!(segmenter.gap_scores("あい"), [i32::MAX]);
+
+    let model = Model::parse("BIAS\t-2147483648\nUW3\tあ\t-2147483648\nUW4\tい\t-2147483648\n").unwrap();
+    assert_eq!(Segmenter::with_model(model).segment("あい"), ["あい"]);
+}
+
+#[test]
+fn parse_errors_name_their_line() {
+    let error = |text: &str| Model::parse(text).unwrap_err().to_string();
+    assert_eq!(error("BIAS\t-332\nUW4\tあ\tx\n"), "line 2: invalid weight `x`");
+    assert_eq!(error("BIAS\t-332\n\nUW4\tあ\t1\t2\n"), "line 3: expected 2 or 3 tab-separated fields, found 4");
+    assert!(error("BIAS\t0\nTEMPLATE\tUW4\tX9\n").starts_with("line 2: "));
+    assert!(matches!(Model::parse("UW4\tあ\t1\n"), Err(ModelError::MissingBias)));
+}
+
+#[test]
+fn complete_models_have_a_table_for_exactly_their_templates() {
+    let templates = "TEMPLATE\tUW3\tW-1\nTEMPLATE\tUW4\tW1\nBIAS\t0\n";
+    let complete = Model::parse(&format!("{}UW3\tあ\t1\nUW4\tい\t2\n", templates)).unwrap();
+    assert!(complete.check_complete().is_ok());
+    let missing = Model::parse(&format!("{}UW3\tあ\t1\n", templates)).unwrap();
+    assert!(matches!(missing.check_complete(), Err(ModelError::MissingTemplates(names)) if names == ["UW4"]));
+    let unknown = Model::parse(&format!("{}UW3\tあ\t1\nUW4\tい\t2\nBW1\tあい\t3\n", templates)).unwrap();
+    assert!(matches!(unknown.check_complete(), Err(ModelError::UnknownTemplate(name)) if name == "BW1"));
+    assert!(Model::builtin().check_complete().is_ok());
+}
+
+#[test]
+fn generated_module_round_trips_through_from_static() {
+    let model = Model::parse("TEMPLATE\tUW3\tW-1\nTEMPLATE\tBW2\tW-1 W1\nBIAS\t-5\nUW3\t\"\\\t1\nBW2\tあい\t-2\n").unwrap();
+    let module = generate_rust_module(&model, "test.model").unwrap();
+    assert!(module.starts_with("// @generated by tinysegmenter-codegen from test.model. Do not edit.\n"));
+    assert!(module.contains("pub static UW3: &[(&str, i32)] = &[\n    (\"\\\"\\\\\", 1),\n];\n"), "{}", module);
+    assert!(module.contains("    (\"BW2\", BW2),\n"), "{}", module);
+}
+
+#[test]
+fn template_names_that_are_not_table_identifiers_are_rejected() {
+    for name in ["uw4", "W-1", "4UW", "TEMPLATES", "TABLES"] {
+        let model = Model::parse(&format!("TEMPLATE\t{}\tW1\nBIAS\t0\n{}\tあ\t1\n", name, name)).unwrap();
+        assert!(
+            matches!(generate_rust_module(&model, "test.model"), Err(ModelError::InvalidIdentifier(found)) if found == name),
+            "{}", name
+        );
+    }
+}
+
+#[test]
+fn default_model_is_generated_from_the_model_file() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let model = Model::load(root.join("models/default.model")).unwrap();
+    model.check_complete().unwrap();
+    let generated = generate_rust_module(&model, "models/default.model").unwrap();
+    let path = root.join("src/default_model.rs");
+    if env::var_os("UPDATE_DEFAULT_MODEL").is_some() {
+        fs::write(&path, &generated).unwrap();
+    }
+    let module = fs::read_to_string(&path).unwrap();
+    assert!(module == generated, "{} is out of date; rerun with UPDATE_DEFAULT_MODEL=1", path.display());
+    assert_eq!(Model::builtin(), model);
+}
+
+#[test]
+fn codegen_requires_a_file_name_after_the_output_flag() {
+    let output = Command::new(env!("CARGO_BIN_EXE_tinysegmenter-codegen"))
+        .args(["models/default.model", "-o"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output.stdout.is_empty());
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "-o needs a file name\n");
+}
@@ -0,0 +1,69 @@
+//! N-best segmentation and lattices.
+
+use tinysegmenter::{DecodeMode, Lattice, Segmenter, SegmenterConfig};
+
+const TEXT: &str = "私の名前は中野です";
+
+#[test]
+fn nbest_is_sorted_and_distinct() {
+    let segmenter = Segmenter::new();
+    let nbest = segmenter.segment_nbest(TEXT, 10);
+    assert_eq!(nbest.len(), 10);
+    for pair in nbest.windows(2) {
+        assert!(pair[0].score >= pair[1].score);
+    }
+    for (i, a) in nbest.iter().enumerate() {
+        assert_eq!(a.words.concat(), TEXT);
+        assert!(nbest[i + 1..].iter().all(|b| b.words != a.words));
+    }
+}
+
+#[test]
+fn best_score_sums_the_greedy_margins() {
+    let segmenter = Segmenter::new();
+    let best = &segmenter.segment_nbest(TEXT, 1)[0];
+    let margins: i64 = segmenter.gap_scores(TEXT).iter().map(|s| s.unsigned_abs() as i64).sum();
+    assert_eq!(best.score, margins);
+    assert_eq!(best.words, segmenter.segment(TEXT));
+}
+
+#[test]
+fn single_best_follows_the_configured_decoder() {
+    let viterbi = Segmenter::new().with_config(SegmenterConfig { decode: DecodeMode::Viterbi, ..Default::default() });
+    let text = "東京都に行きました";
+    let best = &viterbi.segment_nbest(text, 1)[0];
+    assert_eq!(best.words, ["東", "京都", "に", "行き", "まし", "た"]);
+    assert_eq!(best.words, viterbi.segment(text));
+    assert_ne!(best.words, Segmenter::new().segment_nbest(text, 1)[0].words);
+    // Viterbi maximises the score the beam searches for.
+    assert!(viterbi.segment_nbest(text, 5).iter().all(|s| s.score <= best.score));
+}
+
+#[test]
+fn short_texts_enumerate_every_segmentation() {
+    let segmenter = Segmenter::new();
+    assert_eq!(segmenter.segment_nbest("東京都", 10).len(), 4);
+    assert_eq!(segmenter.segment_nbest("東", 10).len(), 1);
+    assert!(segmenter.segment_nbest("", 10).is_empty());
+    assert!(segmenter.segment_nbest(TEXT, 0).is_empty());
+}
+
+#[test]
+fn lattice_keeps_the_best_score_of_each_word() {
+    let segmenter = Segmenter::new();
+    let nbest = segmenter.segment_nbest(TEXT, 5);
+    let lattice = Lattice::from_nbest(&nbest);
+    assert_eq!(lattice, segmenter.lattice(TEXT, 5));
+    for segmentation in &nbest {
+        let mut start = 0;
+        for word in &segmentation.words {
+            let end = start + word.chars().count();
+            let entry = lattice.words.iter().find(|w| w.start == start && w.end == end).unwrap();
+            assert_eq!(&entry.surface, word);
+            assert!(entry.score >= segmentation.score);
+            start = end;
+        }
+    }
+    let first = &lattice.words[0];
+    assert_eq!(lattice.to_tsv().lines().next().unwrap(), format!("0\t{}\t{}\t{}", first.end, first.surface, first.score));
+}
@@ -0,0 +1,84 @@
+//! Property tests for invariants that must hold for any input text.
+
+use std::sync::OnceLock;
+
+use proptest::prelude::*;
+use tinysegmenter::{DecodeMode, Segmenter, SegmenterConfig};
+
+fn segmenter() -> &'static Segmenter {
+    static SEGMENTER: OnceLock<Segmenter> = OnceLock::new();
+    SEGMENTER.get_or_init(Segmenter::new)
+}
+
+/// Characters of every type the scorer distinguishes, plus arbitrary ones.
+fn text() -> impl Strategy<Value = String> {
+    let c = prop_oneof![
+        proptest::char::range('ぁ', 'ん'),
+        proptest::char::range('ァ', 'ヴ'),
+        proptest::char::range('一', '龠'),
+        proptest::char::range('ｱ', 'ﾝ'),
+        proptest::sample::select(vec!['一', '二', '十', '百', 'ー', '々', '。', '、', '「', '」', ' ']),
+        proptest::char::range('a', 'z'),
+        proptest::char::range('0', '9'),
+        any::<char>(),
+    ];
+    proptest::collection::vec(c, 0..40).prop_map(|chars| chars.into_iter().collect())
+}
+
+proptest! {
+    #[test]
+    fn words_concatenate_to_input(text in text()) {
+        prop_assert_eq!(segmenter().segment(&text).concat(), text);
+    }
+
+    #[test]
+    fn no_empty_words(text in text()) {
+        for word in segmenter().segment(&text) {
+            prop_assert!(!word.is_empty());
+        }
+    }
+
+    #[test]
+    fn history_matches_emitted_boundaries(text in text()) {
+        let words = segmenter().segment(&text);
+        let gaps = segmenter().explain(&text);
+        prop_assert_eq!(gaps.len(), text.chars().count().saturating_sub(1));
+
+        // The boundaries reported per gap are exactly those between the words.
+        let mut offsets = Vec::new();
+        let mut offset = 0;
+        for word in words.iter().take(words.len().saturating_sub(1)) {
+            offset += word.chars().count();
+            offsets.push(offset);
+        }
+        let boundaries: Vec<usize> = gaps.iter().filter(|g| g.boundary).map(|g| g.position).collect();
+        prop_assert_eq!(&boundaries, &offsets);
+
+        // UP1..UP3 at each gap are the decisions of the three gaps before it.
+        let tags: Vec<String> = gaps.iter().map(|g| String::from(if g.boundary { "B" } else { "O" })).collect();
+        for (k, gap) in gaps.iter().enumerate() {
+            for (template, back) in [("UP1", 3), ("UP2", 2), ("UP3", 1)] {
+                let expected = if k >= back { tags[k - back].clone() } else { String::from("U") };
+                let seen = &gap.contributions.iter().find(|c| c.template == template).unwrap().feature;
+                prop_assert_eq!(seen, &expected, "{} at gap {}", template, gap.position);
+            }
+        }
+    }
+
+    #[test]
+    fn best_of_one_is_the_greedy_segmentation(text in text()) {
+        let best = segmenter().segment_nbest(&text, 1);
+        prop_assert_eq!(best.first().map(|s| s.words.clone()).unwrap_or_default(), segmenter().segment(&text));
+    }
+
+    #[test]
+    fn viterbi_words_concatenate_to_input(text in text()) {
+        static VITERBI: OnceLock<Segmenter> = OnceLock::new();
+        let viterbi = VITERBI.get_or_init(|| {
+            Segmenter::new().with_config(SegmenterConfig { decode: DecodeMode::Viterbi, ..Default::default() })
+        });
+        let words = viterbi.segment(&text);
+        prop_assert_eq!(words.concat(), text);
+        prop_assert!(words.iter().all(|w| !w.is_empty()));
+    }
+}
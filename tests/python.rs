@@ -0,0 +1,72 @@
+//! Imports the Python bindings from the cdylib and checks them against the Rust API.
+#![cfg(all(unix, feature = "python"))]
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use tinysegmenter::compress::parse_any;
+use tinysegmenter::Segmenter;
+
+const TEXTS: [&str; 5] = [
+    "私の名前は中野です",
+    "コンピューターの使い方がよくわかりません。",
+    "iPhone 15を買った",
+    "東",
+    "",
+];
+
+/// The lines `tests/python/bindings.py` prints for `segmenter`.
+fn expected(segmenter: &Segmenter) -> String {
+    let mut lines = Vec::new();
+    for text in TEXTS {
+        let words = segmenter.segment(text);
+        lines.push(["tokenize".to_string()].into_iter().chain(words.iter().cloned()).collect::<Vec<_>>());
+        let mut start = 0;
+        lines.push(["offsets".to_string()].into_iter().chain(words.iter().map(|w| {
+            let end = start + w.chars().count();
+            let span = format!("{}:{}:{}", w, start, end);
+            start = end;
+            span
+        })).collect());
+        lines.push(["scores".to_string()].into_iter().chain(segmenter.scored_gaps(text).iter().map(|g| format!("{}:{}", g.score, u8::from(g.boundary)))).collect());
+        lines.push(["confidence".to_string()].into_iter().chain(segmenter.segment_with_margins(text).iter().map(|(w, m)| format!("{}:{}", w, m))).collect());
+    }
+    lines.iter().map(|l| l.join("\t") + "\n").collect::<String>() + "ok\n"
+}
+
+#[test]
+fn python_bindings_match_the_rust_api() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let deps = env::current_exe().unwrap().parent().unwrap().to_path_buf();
+    let library = deps.join(format!("{}tinysegmenter{}", env::consts::DLL_PREFIX, env::consts::DLL_SUFFIX));
+    assert!(library.exists(), "{} was not built", library.display());
+
+    // Python imports the module `tinysegmenter` from `tinysegmenter.so`.
+    let out = env::temp_dir().join(format!("tinysegmenter-python-{}", std::process::id()));
+    fs::create_dir_all(&out).unwrap();
+    fs::copy(&library, out.join("tinysegmenter.so")).unwrap();
+    let texts = out.join("texts.txt");
+    fs::write(&texts, TEXTS.map(|t| format!("{}\n", t)).concat()).unwrap();
+    let model = out.join("split.model");
+    fs::write(&model, "BIAS\t-1\n").unwrap();
+
+    let runs = [
+        (Vec::new(), Segmenter::new()),
+        (vec![model.clone()], Segmenter::with_model(parse_any("BIAS\t-1\n").unwrap())),
+    ];
+    for (args, segmenter) in runs {
+        let output = Command::new(env::var("PYTHON").unwrap_or_else(|_| String::from("python3")))
+            .arg(root.join("tests/python/bindings.py"))
+            .arg(&texts)
+            .args(&args)
+            .env("PYTHONPATH", &out)
+            .output()
+            .expect("a Python interpreter is needed for this test");
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(output.status.success(), "{}{}", stdout, String::from_utf8_lossy(&output.stderr));
+        assert_eq!(stdout, expected(&segmenter));
+    }
+    fs::remove_dir_all(&out).unwrap();
+}
@@ -0,0 +1,45 @@
+"""Exercises the Python bindings; run by tests/python.rs.
+
+Usage: bindings.py TEXTS [MODEL]. Prints, for every line of TEXTS, one line
+per method with its results joined by tabs, then "ok".
+"""
+
+import sys
+import threading
+
+import tinysegmenter
+
+texts = open(sys.argv[1], encoding="utf-8").read().splitlines()
+segmenter = tinysegmenter.TinySegmenter(*sys.argv[2:])
+
+for text in texts:
+    words = segmenter.tokenize(text)
+    print("\t".join(["tokenize"] + words))
+    offsets = segmenter.tokenize_with_offsets(text)
+    assert all(text[start:end] == word for word, start, end in offsets)
+    print("\t".join(["offsets"] + ["%s:%d:%d" % span for span in offsets]))
+    print("\t".join(["scores"] + ["%d:%d" % gap for gap in segmenter.gap_scores(text)]))
+    print("\t".join(["confidence"] + ["%s:%d" % pair for pair in segmenter.tokenize_with_confidence(text)]))
+
+# Batches from several threads at once agree with one-by-one segmentation.
+expected = [segmenter.tokenize(text) for text in texts]
+results = [None] * 4
+
+def run(i):
+    results[i] = segmenter.tokenize_batch(texts * 50)
+
+threads = [threading.Thread(target=run, args=(i,)) for i in range(len(results))]
+for thread in threads:
+    thread.start()
+for thread in threads:
+    thread.join()
+assert all(result == expected * 50 for result in results)
+
+try:
+    tinysegmenter.TinySegmenter(sys.argv[1])
+except ValueError:
+    pass
+else:
+    raise AssertionError("a text file loaded as a model")
+
+print("ok")
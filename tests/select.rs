@@ -0,0 +1,96 @@
+//! Picking uncertain gaps for annotation.
+
+mod common;
+
+use tinysegmenter::corpus::{read_partial, Gap};
+use tinysegmenter::eval::boundaries;
+use tinysegmenter::select::{annotate_gaps, annotate_sentences, rank_gaps, ScoredGap};
+use tinysegmenter::{RunGrouping, Segmenter, SegmenterConfig};
+
+#[test]
+fn gap_scores_agree_with_segment() {
+    let segmenter = Segmenter::new();
+    for text in common::texts() {
+        let scores = segmenter.gap_scores(&text);
+        assert_eq!(scores.len(), text.chars().count() - 1);
+        let predicted: Vec<usize> = (1..=scores.len()).filter(|p| scores[p - 1] > 0).collect();
+        assert_eq!(predicted, boundaries(&segmenter.segment(&text)));
+    }
+    assert!(segmenter.gap_scores("").is_empty());
+}
+
+/// Greedy, unforced gaps with these scores.
+fn scored(sentences: &[&[i32]]) -> Vec<Vec<ScoredGap>> {
+    sentences.iter()
+        .map(|scores| scores.iter().map(|&score| ScoredGap { score, boundary: score > 0, forced: false }).collect())
+        .collect()
+}
+
+#[test]
+fn gaps_are_ranked_by_margin_after_disagreements() {
+    let primary = scored(&[&[300, -20], &[-5, 40]]);
+    let secondary = scored(&[&[-10, -20], &[-5, 40]]);
+
+    let by_margin: Vec<(usize, usize)> = rank_gaps(&primary, None).iter().map(|c| (c.sentence, c.position)).collect();
+    assert_eq!(by_margin, [(1, 1), (0, 2), (1, 2), (0, 1)]);
+
+    let ranked = rank_gaps(&primary, Some(&secondary));
+    assert!(ranked[0].disagreement);
+    assert_eq!((ranked[0].sentence, ranked[0].position), (0, 1));
+    assert!(ranked[1..].iter().all(|c| !c.disagreement));
+}
+
+#[test]
+fn decisions_not_scores_are_ranked_and_guessed() {
+    // The second gap of the first sentence scores as a split but was decided
+    // against, as Viterbi decoding may; the first was forced.
+    let mut primary = scored(&[&[300, 20], &[-5, 40]]);
+    primary[0][0].forced = true;
+    primary[0][1].boundary = false;
+    let mut secondary = primary.clone();
+    secondary[0][1].boundary = true;
+
+    let ranked = rank_gaps(&primary, Some(&secondary));
+    assert_eq!(ranked.len(), 3);
+    assert!(ranked.iter().all(|c| (c.sentence, c.position) != (0, 1)));
+    assert_eq!((ranked[0].sentence, ranked[0].position, ranked[0].disagreement), (0, 2, true));
+    let annotated = annotate_gaps(&common::texts(), &primary, &ranked[..1]);
+    assert_eq!(annotated[0].gap(2), Gap::Inside);
+}
+
+#[test]
+fn grouped_runs_are_forced_gaps() {
+    let segmenter = Segmenter::new().with_config(SegmenterConfig { runs: RunGrouping::all(), ..Default::default() });
+    let gaps = segmenter.scored_gaps("iPhone15を");
+    assert!(gaps[..7].iter().all(|g| g.forced && !g.boundary));
+    assert!(!gaps[7].forced);
+}
+
+#[test]
+fn selected_gaps_form_a_partial_annotation() {
+    let segmenter = Segmenter::new();
+    let texts = common::texts();
+    let scores: Vec<Vec<ScoredGap>> = texts.iter().map(|t| segmenter.scored_gaps(t)).collect();
+    let ranked = rank_gaps(&scores, None);
+
+    let selected = annotate_gaps(&texts, &scores, &ranked[..5]);
+    assert_eq!(selected.iter().map(|a| a.labelled()).sum::<usize>(), 5);
+    let first = &ranked[0];
+    assert_eq!(selected[0].text, texts[first.sentence]);
+    assert_ne!(selected[0].gap(first.position), Gap::Unknown);
+
+    let rendered: String = selected.iter().map(|a| a.render() + "\n").collect();
+    assert_eq!(read_partial(&rendered).unwrap(), selected);
+}
+
+#[test]
+fn selected_sentences_are_fully_annotated() {
+    let segmenter = Segmenter::new();
+    let texts = common::texts();
+    let scores: Vec<Vec<ScoredGap>> = texts.iter().map(|t| segmenter.scored_gaps(t)).collect();
+    let selected = annotate_sentences(&texts, &scores, &rank_gaps(&scores, None), 2);
+    assert_eq!(selected.len(), 2);
+    for annotation in &selected {
+        assert_eq!(annotation.labelled(), annotation.gaps.len());
+    }
+}
@@ -0,0 +1,78 @@
+//! The tantivy tokenizer, with filters and an in-memory index.
+#![cfg(feature = "tantivy")]
+
+use tantivy::collector::TopDocs;
+use tantivy::query::QueryParser;
+use tantivy::schema::{IndexRecordOption, Schema, TextFieldIndexing, TextOptions, STORED};
+use tantivy::tokenizer::{LowerCaser, StopWordFilter, TextAnalyzer, Token, TokenStream};
+use tantivy::{doc, Index, TantivyDocument};
+use tinysegmenter::tantivy_tokenizer::SegmenterTokenizer;
+use tinysegmenter::Segmenter;
+
+fn analyzer() -> TextAnalyzer {
+    TextAnalyzer::builder(SegmenterTokenizer::new(Segmenter::new()))
+        .filter(LowerCaser)
+        .filter(StopWordFilter::remove(vec![String::from("の"), String::from("を")]))
+        .build()
+}
+
+fn tokens(text: &str) -> Vec<Token> {
+    let mut analyzer = analyzer();
+    let mut stream = analyzer.token_stream(text);
+    let mut tokens = Vec::new();
+    stream.process(&mut |token| tokens.push(token.clone()));
+    tokens
+}
+
+#[test]
+fn offsets_point_into_the_text() {
+    let text = " 私の名前は  中野です";
+    let tokens = tokens(text);
+    for token in &tokens {
+        assert_eq!(&text[token.offset_from..token.offset_to], token.text);
+    }
+    let words: Vec<&str> = tokens.iter().map(|t| t.text.as_str()).collect();
+    assert_eq!(words, ["私", "名前", "は", "中野", "です"]);
+    // Stopwords keep their positions, so phrases do not span them.
+    let positions: Vec<usize> = tokens.iter().map(|t| t.position).collect();
+    assert_eq!(positions, [0, 2, 3, 4, 5]);
+}
+
+#[test]
+fn filters_apply_to_segmented_words() {
+    let words: Vec<String> = tokens("iPhoneを買った").into_iter().map(|t| t.text).collect();
+    assert_eq!(words[0], "iphone");
+    assert!(!words.contains(&String::from("を")));
+    assert!(tokens("").is_empty());
+}
+
+#[test]
+fn in_memory_index_finds_words_and_phrases() {
+    let mut schema = Schema::builder();
+    let indexing = TextFieldIndexing::default().set_tokenizer("ja").set_index_option(IndexRecordOption::WithFreqsAndPositions);
+    let body = schema.add_text_field("body", TextOptions::default().set_indexing_options(indexing) | STORED);
+    let index = Index::create_in_ram(schema.build());
+    index.tokenizers().register("ja", analyzer());
+
+    let mut writer = index.writer(15_000_000).unwrap();
+    writer.add_document(doc!(body => "東京都に行きました")).unwrap();
+    writer.add_document(doc!(body => "私の名前は中野です")).unwrap();
+    writer.add_document(doc!(body => "コンピューターの使い方")).unwrap();
+    writer.commit().unwrap();
+
+    let searcher = index.reader().unwrap().searcher();
+    let parser = QueryParser::for_index(&index, vec![body]);
+    let hits = |query: &str| -> Vec<String> {
+        let query = parser.parse_query(query).unwrap();
+        searcher.search(&query, &TopDocs::with_limit(10)).unwrap().into_iter()
+            .map(|(_, address)| {
+                let doc: TantivyDocument = searcher.doc(address).unwrap();
+                doc.get_first(body).and_then(|v| tantivy::schema::Value::as_str(&v)).unwrap().to_string()
+            })
+            .collect()
+    };
+    assert_eq!(hits("名前"), ["私の名前は中野です"]);
+    assert_eq!(hits("\"中野です\""), ["私の名前は中野です"]);
+    assert_eq!(hits("コンピューター"), ["コンピューターの使い方"]);
+    assert!(hits("名").is_empty());
+}
@@ -0,0 +1,54 @@
+//! Declarative feature templates.
+
+mod common;
+
+use tinysegmenter::template::{default_templates, is_default, Template};
+use tinysegmenter::train::{update_model, Algorithm};
+use tinysegmenter::{Model, Segmenter, DEFAULT_TEMPLATES};
+
+#[test]
+fn specs_round_trip() {
+    for (name, spec) in DEFAULT_TEMPLATES {
+        assert_eq!(Template::parse(name, spec).unwrap().spec(), spec);
+    }
+    assert_eq!(Template::parse("QW1", "W-2  W-1 W+1 W2").unwrap().spec(), "W-2 W-1 W1 W2");
+}
+
+#[test]
+fn invalid_specs_are_rejected() {
+    for (name, spec) in [("X", ""), ("X", "W0"), ("X", "P1"), ("X", "Z-1"), ("X", "W"), ("BIAS", "W1"), ("A:B", "W1")] {
+        assert!(Template::parse(name, spec).is_err(), "{} {:?}", name, spec);
+    }
+}
+
+#[test]
+fn builtin_model_uses_default_templates() {
+    let model = Model::builtin();
+    assert!(is_default(&model.templates));
+    assert!(!model.to_native().contains("TEMPLATE\t"));
+}
+
+fn custom_model() -> Model {
+    let text = "TEMPLATE\tQW1\tW-2 W-1 W1 W2\nTEMPLATE\tUC4\tC1\nTEMPLATE\tUP3\tP-1\nBIAS\t0\n";
+    Model::parse(text).unwrap()
+}
+
+#[test]
+fn templates_are_stored_in_the_model_file() {
+    let model = custom_model();
+    assert_eq!(model.templates.iter().map(|t| t.name.as_str()).collect::<Vec<_>>(), ["QW1", "UC4", "UP3"]);
+    assert_eq!(Model::parse(&model.to_native()).unwrap(), model);
+    assert_ne!(model.templates, default_templates());
+}
+
+#[test]
+fn custom_templates_train_and_score() {
+    let corpus = common::annotated("東京 都 に 行く\n京都 に 行く\n");
+    let model = update_model(&custom_model(), &corpus, Algorithm::PassiveAggressive { c: 1000.0, margin: 100.0 }, 5);
+    assert!(model.tables["QW1"].contains_key("京都に行"));
+
+    let segmenter = Segmenter::with_model(Model::parse(&model.to_native()).unwrap());
+    assert_eq!(segmenter.segment("京都に行く"), ["京都", "に", "行く"]);
+    let explained = segmenter.explain("京都");
+    assert_eq!(explained[0].contributions.iter().map(|c| c.key()).collect::<Vec<_>>(), ["QW1:B1京都E1", "UC4:H", "UP3:U"]);
+}
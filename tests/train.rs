@@ -0,0 +1,125 @@
+//! Online learning from corrected sentences.
+
+mod common;
+
+use std::env;
+use std::fs;
+use std::process::Command;
+
+use tinysegmenter::corpus::{read_partial, Annotation};
+use tinysegmenter::eval::{read_segmented, BoundaryScores};
+use tinysegmenter::train::{cross_validate, update_model, Algorithm, CrossValidation, OnlineLearner};
+use tinysegmenter::{Model, Segmenter};
+
+fn corrections() -> Vec<Vec<String>> {
+    read_segmented("コンピューター の 使い方 が よく わかり ませ ん 。\n経済 対策 を 発表 し た 。\n")
+}
+
+fn assert_learns(algorithm: Algorithm) {
+    let corpus = corrections();
+    let before = Segmenter::new();
+    assert_ne!(before.segment(&corpus[0].concat()), corpus[0]);
+
+    let sentences: Vec<Annotation> = corpus.iter().map(|words| Annotation::from_words(words)).collect();
+    let model = update_model(&Model::builtin(), &sentences, algorithm, 5);
+    let after = Segmenter::with_model(Model::parse(&model.to_native()).unwrap());
+    for words in &corpus {
+        assert_eq!(&after.segment(&words.concat()), words);
+    }
+}
+
+#[test]
+fn averaged_perceptron_fixes_corrected_sentences() {
+    assert_learns(Algorithm::AveragedPerceptron { rate: 100.0 });
+}
+
+#[test]
+fn passive_aggressive_fixes_corrected_sentences() {
+    assert_learns(Algorithm::PassiveAggressive { c: 1000.0, margin: 1000.0 });
+}
+
+#[test]
+fn no_update_for_sentences_already_correct() {
+    let corpus = read_segmented("私 の 名前 は 西村 です\n");
+    let sentences = [Annotation::from_words(&corpus[0])];
+    let model = update_model(&Model::builtin(), &sentences, Algorithm::PassiveAggressive { c: 1000.0, margin: 0.0 }, 3);
+    assert_eq!(model.bias, Model::builtin().bias);
+    assert_eq!(Segmenter::with_model(model).segment("私の名前は西村です"), corpus[0]);
+}
+
+#[test]
+fn partial_annotation_round_trips() {
+    let line = "コ-ン ピ-ュ|ー タ ー";
+    let sentences = read_partial(line).unwrap();
+    assert_eq!(sentences[0].text, "コンピューター");
+    assert_eq!(sentences[0].labelled(), 3);
+    assert_eq!(sentences[0].render(), line);
+    assert!(read_partial("東x京").is_err());
+    assert!(read_partial("東|").is_err());
+}
+
+#[test]
+fn full_annotation_matches_word_boundaries() {
+    let words = read_segmented("東京 に 行く\n").remove(0);
+    assert_eq!(Annotation::from_words(&words).render(), "東-京|に|行-く");
+}
+
+#[test]
+fn only_labelled_gaps_are_learned() {
+    // Only the gap the built-in model misses, between よく and わかり, is labelled.
+    let sentences = read_partial("コ ン ピ ュ ー タ ー の 使 い 方 が よ く|わ か り ま せ ん 。").unwrap();
+    let mut learner = OnlineLearner::new(&Model::builtin(), Algorithm::PassiveAggressive { c: 1000.0, margin: 1000.0 });
+    assert_eq!(learner.learn(&sentences[0]), 1);
+
+    let after = Segmenter::with_model(learner.model());
+    let words = after.segment(&sentences[0].text);
+    assert!(words.windows(2).any(|w| w == ["よく", "わかり"]), "{:?}", words);
+}
+
+#[test]
+fn cross_validation_scores_every_fold_after_every_epoch() {
+    let corpus = common::annotated("私 の 名前 は 西村 です\n経済 対策 を 発表 し た 。\n今日 は いい 天気 です ね 。\n");
+    let cv = cross_validate(&Model::builtin(), None, &corpus, Algorithm::default(), 3, 2, 0);
+    assert_eq!(cv.f1.len(), 3);
+    assert_eq!(cv.epochs(), 2);
+    let (mean, std) = cv.summary(2);
+    assert!((0.0..=1.0).contains(&mean));
+    assert!(std >= 0.0);
+
+    let uniform = CrossValidation { f1: vec![vec![0.5], vec![0.7]] };
+    let (mean, std) = uniform.summary(1);
+    assert!((mean - 0.6).abs() < 1e-9);
+    assert!((std - 0.02f64.sqrt()).abs() < 1e-9);
+}
+
+#[test]
+fn partial_gold_scores_only_labelled_gaps() {
+    let gold = read_partial("東-京|都 に").unwrap();
+    let mut scores = BoundaryScores::default();
+    scores.add_annotated(&gold[0], &["東".to_string(), "京".to_string(), "都に".to_string()]);
+    assert_eq!((scores.true_positives, scores.false_positives, scores.false_negatives), (1, 1, 0));
+}
+
+#[test]
+fn cross_validation_rejects_a_corpus_too_small_to_split() {
+    let dir = env::temp_dir().join(format!("tinysegmenter-cv-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    for (sentences, expected) in [
+        ("", "error: --cv needs a corpus of at least 2 sentences, found 0"),
+        ("経済 対策 を 発表 し た 。\n", "error: --cv needs a corpus of at least 2 sentences, found 1"),
+        ("経済 対策 。\n発表 し た 。\n", "error: --cv needs between 2 and 2 folds"),
+    ] {
+        let corpus = dir.join("corpus.txt");
+        fs::write(&corpus, sentences).unwrap();
+        let output = Command::new(env!("CARGO_BIN_EXE_tinysegmenter"))
+            .arg("train")
+            .arg("--corpus")
+            .arg(&corpus)
+            .args(["--cv", "3"])
+            .output()
+            .unwrap();
+        assert!(!output.status.success());
+        assert_eq!(String::from_utf8_lossy(&output.stderr).lines().next(), Some(expected));
+    }
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,45 @@
+//! Exact decoding over the history states.
+
+use tinysegmenter::eval::boundaries;
+use tinysegmenter::{DecodeMode, Model, Segmenter, SegmenterConfig};
+
+fn viterbi(model: Model) -> Segmenter {
+    Segmenter::with_model(model).with_config(SegmenterConfig { decode: DecodeMode::Viterbi, ..Default::default() })
+}
+
+const TEXTS: [&str; 4] = ["私の名前は中野です", "東京都に行きました", "今日はいい天気ですね。", "彼は昨日東京から"];
+
+#[test]
+fn viterbi_finds_the_best_of_every_segmentation() {
+    let greedy = Segmenter::new();
+    let exact = viterbi(Model::builtin());
+    for text in TEXTS {
+        // A beam as wide as the search space keeps every segmentation.
+        let all = greedy.segment_nbest(text, 1 << (text.chars().count() - 1));
+        let best = &all[0];
+        assert_eq!(exact.segment(text), best.words, "{}", text);
+        assert!(best.score >= greedy.segment_nbest(text, 1)[0].score);
+    }
+}
+
+#[test]
+fn explanations_follow_the_viterbi_path() {
+    let exact = viterbi(Model::builtin());
+    for text in TEXTS {
+        let split: Vec<usize> = exact.explain(text).iter().filter(|g| g.boundary).map(|g| g.position).collect();
+        assert_eq!(split, boundaries(&exact.segment(text)));
+    }
+}
+
+#[test]
+fn without_history_features_viterbi_is_greedy() {
+    let text = "TEMPLATE\tUW3\tW-1\nTEMPLATE\tUC4\tC1\nBIAS\t-100\nUW3\tの\t500\nUC4\tH\t300\nUC4\tK\t-200\n";
+    let model = Model::parse(text).unwrap();
+    let greedy = Segmenter::with_model(model.clone());
+    let exact = viterbi(model);
+    for text in TEXTS {
+        assert_eq!(exact.segment(text), greedy.segment(text));
+    }
+    assert_eq!(exact.segment("の"), ["の"]);
+    assert!(exact.segment("").is_empty());
+}
@@ -0,0 +1,72 @@
+//! Builds the wasm module and runs the JavaScript API over it in Node.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use tinysegmenter::compress::parse_any;
+use tinysegmenter::Segmenter;
+
+const TARGET: &str = "wasm32-unknown-unknown";
+
+/// The size budget of the module with the small built-in model; see the
+/// WebAssembly section of the README.
+const MAX_MODULE_BYTES: u64 = 144 * 1024;
+
+fn succeeds(command: &mut Command) -> bool {
+    command.output().is_ok_and(|output| output.status.success())
+}
+
+/// Whether the standard library for the wasm target is installed.
+fn has_target() -> bool {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+    Command::new(rustc)
+        .args(["--print", "target-libdir", "--target", TARGET])
+        .output()
+        .is_ok_and(|output| {
+            output.status.success() && Path::new(String::from_utf8_lossy(&output.stdout).trim()).is_dir()
+        })
+}
+
+#[test]
+fn javascript_api_segments_like_the_rust_api() {
+    if !succeeds(Command::new("node").arg("--version")) || !has_target() {
+        eprintln!("skipped: needs node and the {} target", TARGET);
+        return;
+    }
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let status = Command::new(env::var("CARGO").unwrap_or_else(|_| String::from("cargo")))
+        .current_dir(root)
+        .args(["build", "--lib", "--target", TARGET, "--profile", "wasm", "--no-default-features", "--features", "small-model"])
+        .status()
+        .unwrap();
+    assert!(status.success());
+    let module = root.join("target/wasm32-unknown-unknown/wasm/tinysegmenter.wasm");
+    let size = fs::metadata(&module).unwrap().len();
+    assert!(size <= MAX_MODULE_BYTES, "the module grew to {} bytes, over {}", size, MAX_MODULE_BYTES);
+
+    let out = env::temp_dir().join(format!("tinysegmenter-wasm-{}", std::process::id()));
+    fs::create_dir_all(&out).unwrap();
+    let model = out.join("split.model");
+    fs::write(&model, "BIAS\t-1\n").unwrap();
+    let output = Command::new("node")
+        .arg(root.join("tests/wasm/segment.mjs"))
+        .arg(&module)
+        .arg(root.join("models/default.model"))
+        .arg(&model)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "{}{}", stdout, String::from_utf8_lossy(&output.stderr));
+
+    let small = parse_any(&fs::read_to_string(root.join("models/default.q8.model")).unwrap()).unwrap();
+    let mut expected = String::new();
+    for segmenter in [Segmenter::with_model(small), Segmenter::new(), Segmenter::with_model(parse_any("BIAS\t-1\n").unwrap())] {
+        for text in ["私の名前は中野です", "😀東京都", ""] {
+            expected += &(segmenter.segment(text).join(" ") + "\n");
+        }
+    }
+    assert_eq!(stdout, expected + "ok\n");
+    fs::remove_dir_all(&out).unwrap();
+}
@@ -0,0 +1,32 @@
+// Exercises the JavaScript API over the wasm build; run by tests/wasm.rs.
+//
+// Usage: node segment.mjs MODULE.wasm MODEL... Prints the words of a few texts
+// with the built-in model, then with each MODEL, one text per line, then "ok".
+
+import { readFileSync } from "node:fs";
+import { init } from "../../js/tinysegmenter.mjs";
+
+const texts = ["私の名前は中野です", "😀東京都", ""];
+const module = await init(readFileSync(process.argv[2]));
+
+const segmenters = [module.segmenter(), ...process.argv.slice(3).map((model) => module.loadModel(readFileSync(model)))];
+for (const segmenter of segmenters) {
+  for (const text of texts) {
+    const tokens = segmenter.segmentWithOffsets(text);
+    for (const { word, start, end } of tokens) {
+      if (text.slice(start, end) !== word) {
+        throw new Error(`${word} is not at ${start}..${end} of ${text}`);
+      }
+    }
+    console.log(segmenter.segment(text).join(" "));
+  }
+  segmenter.free();
+}
+
+try {
+  module.loadModel("not a model");
+  throw new Error("a text loaded as a model");
+} catch (e) {
+  if (e.message === "a text loaded as a model") throw e;
+}
+console.log("ok");